@@ -0,0 +1,34 @@
+use clap::{App, Arg};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn arg_names() -> Vec<String> {
+    (0..1000).map(|i| format!("arg{}", i)).collect()
+}
+
+pub fn build_1000_args(c: &mut Criterion) {
+    let names = arg_names();
+    c.bench_function("build_1000_args", |b| {
+        b.iter(|| {
+            names
+                .iter()
+                .map(|n| Arg::new(n.as_str()))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+pub fn build_app_1000_args(c: &mut Criterion) {
+    let names = arg_names();
+    c.bench_function("build_app_1000_args", |b| {
+        b.iter(|| {
+            let mut app = App::new("claptests");
+            for n in &names {
+                app = app.arg(Arg::new(n.as_str()).long(n.as_str()));
+            }
+            app.get_matches_from(vec![""])
+        })
+    });
+}
+
+criterion_group!(benches, build_1000_args, build_app_1000_args);
+criterion_main!(benches);
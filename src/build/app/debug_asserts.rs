@@ -89,8 +89,8 @@ pub(crate) fn assert_app(app: &App) {
         );
 
         // Long conflicts
-        if let Some(l) = arg.long {
-            if let Some((first, second)) = app.two_args_of(|x| x.long == Some(l)) {
+        if let Some(l) = arg.long.as_deref() {
+            if let Some((first, second)) = app.two_args_of(|x| x.long.as_deref() == Some(l)) {
                 panic!(
                     "Long option names must be unique for each argument, \
                         but '--{}' is in use by both '{}' and '{}'",
@@ -191,12 +191,6 @@ pub(crate) fn assert_app(app: &App) {
             arg.name
         );
 
-        // validators
-        assert!(
-            arg.validator.is_none() || arg.validator_os.is_none(),
-            "Argument '{}' has both `validator` and `validator_os` set which is not allowed",
-            arg.name
-        );
 
         if arg.value_hint == ValueHint::CommandWithArguments {
             assert!(
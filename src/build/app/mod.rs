@@ -23,7 +23,11 @@ use yaml_rust::Yaml;
 
 // Internal
 use crate::{
-    build::{app::settings::AppFlags, arg::ArgProvider, Arg, ArgGroup, ArgSettings},
+    build::{
+        app::settings::AppFlags,
+        arg::{ArgProvider, RequiresPredicate},
+        Arg, ArgGroup, ArgSettings,
+    },
     mkeymap::MKeyMap,
     output::{fmt::Colorizer, Help, HelpWriter, Usage},
     parse::{ArgMatcher, ArgMatches, Input, Parser},
@@ -217,17 +221,17 @@ impl<'help> App<'help> {
     /// Iterate through the *positionals* that don't have custom heading.
     pub fn get_positionals_with_no_heading(&self) -> impl Iterator<Item = &Arg<'help>> {
         self.get_positionals()
-            .filter(|a| a.get_help_heading().is_none())
+            .filter(|a| a.help_heading.is_none())
     }
 
     /// Iterate through the *flags* that don't have custom heading.
     pub fn get_flags_with_no_heading(&self) -> impl Iterator<Item = &Arg<'help>> {
-        self.get_flags().filter(|a| a.get_help_heading().is_none())
+        self.get_flags().filter(|a| a.help_heading.is_none())
     }
 
     /// Iterate through the *options* that don't have custom heading.
     pub fn get_opts_with_no_heading(&self) -> impl Iterator<Item = &Arg<'help>> {
-        self.get_opts().filter(|a| a.get_help_heading().is_none())
+        self.get_opts().filter(|a| a.help_heading.is_none())
     }
 
     // Get a list of subcommands which contain the provided Argument
@@ -310,6 +314,185 @@ impl<'help> App<'help> {
         }
     }
 
+    /// Get the names of everything the named argument conflicts with, resolving conflicting
+    /// arguments to their names and conflicting groups to the group name. This is the
+    /// name-level companion to [`App::get_arg_conflicts_with`], made for rendering conflict
+    /// information in custom help-like output.
+    ///
+    /// ### Panics
+    ///
+    /// If `arg_id` does not match an argument of this `App`, or the argument conflicts with
+    /// something unknown to it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::new("color").long("color").conflicts_with("no-color"))
+    ///     .arg(Arg::new("no-color").long("no-color"));
+    ///
+    /// assert_eq!(app.arg_conflicts("color"), ["no-color"]);
+    /// ```
+    /// [`App::get_arg_conflicts_with`]: ./struct.App.html#method.get_arg_conflicts_with
+    pub fn arg_conflicts<T: Key>(&self, arg_id: T) -> Vec<&str> {
+        let id = Id::from(arg_id);
+        // Arg ids are normally resolved lazily during `_build`, so fall back to hashing the
+        // name here to keep this usable on an unbuilt `App`.
+        let matches_id =
+            |a: &Arg, id: &Id| a.id == *id || (!a.id_explicit && Id::from(a.name) == *id);
+        let arg = self
+            .args
+            .args()
+            .find(|a| matches_id(a, &id))
+            .expect("App::arg_conflicts: The passed id does not match any argument of the app");
+        arg.blacklist
+            .iter()
+            .map(|id| {
+                self.args
+                    .args()
+                    .find(|a| matches_id(a, id))
+                    .map(|a| a.get_name())
+                    .or_else(|| self.groups.iter().find(|g| g.id == *id).map(|g| g.name))
+                    .expect(
+                        "App::arg_conflicts: \
+                    The arg conflicts with something unknown to the app",
+                    )
+            })
+            .collect()
+    }
+
+    /// Get a list of all arguments the given argument requires, including those only required
+    /// conditionally via [`Arg::requires_if`] and friends.
+    ///
+    /// ### Panics
+    ///
+    /// If the given arg requires an argument that is unknown to this `App`.
+    ///
+    /// [`Arg::requires_if`]: ./struct.Arg.html#method.requires_if
+    pub fn get_arg_requires(&self, arg: &Arg) -> Vec<&Arg<'help>> {
+        arg.requires
+            .iter()
+            .map(|(_, id)| {
+                self.args.args().find(|arg| arg.id == *id).expect(
+                    "App::get_arg_requires: \
+                    The passed arg requires an arg unknown to the app",
+                )
+            })
+            .collect()
+    }
+
+    /// Get a list of all arguments the given argument overrides.
+    ///
+    /// ### Panics
+    ///
+    /// If the given arg overrides an argument that is unknown to this `App`.
+    pub fn get_arg_overrides(&self, arg: &Arg) -> Vec<&Arg<'help>> {
+        arg.overrides
+            .iter()
+            .map(|id| {
+                self.args.args().find(|arg| arg.id == *id).expect(
+                    "App::get_arg_overrides: \
+                    The passed arg overrides an arg unknown to the app",
+                )
+            })
+            .collect()
+    }
+
+    /// Get a list of all [`ArgGroup`]s the given argument is a member of, whether the membership
+    /// was declared on the argument via [`Arg::group`] or on the group via [`ArgGroup::arg`].
+    ///
+    /// [`ArgGroup`]: ./struct.ArgGroup.html
+    /// [`Arg::group`]: ./struct.Arg.html#method.group
+    /// [`ArgGroup::arg`]: ./struct.ArgGroup.html#method.arg
+    pub fn get_arg_groups(&self, arg: &Arg) -> Vec<&ArgGroup<'help>> {
+        self.groups
+            .iter()
+            .filter(|g| g.args.contains(&arg.id) || arg.groups.contains(&g.id))
+            .collect()
+    }
+
+    /// Get the names of all [`ArgGroup`]s the named argument is a member of, whether the
+    /// membership was declared on the argument via [`Arg::group`] or on the group via
+    /// [`ArgGroup::arg`]. Together with [`App::args_in_group`] this lets third-party tooling
+    /// reconstruct the group graph without parsing help text.
+    ///
+    /// ### Panics
+    ///
+    /// If `arg_id` does not match an argument of this `App`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::new("json").long("json"))
+    ///     .arg(Arg::new("yaml").long("yaml"))
+    ///     .group(ArgGroup::new("format").args(&["json", "yaml"]));
+    ///
+    /// assert_eq!(app.groups_for_arg("json"), ["format"]);
+    /// ```
+    /// [`ArgGroup`]: ./struct.ArgGroup.html
+    /// [`Arg::group`]: ./struct.Arg.html#method.group
+    /// [`ArgGroup::arg`]: ./struct.ArgGroup.html#method.arg
+    /// [`App::args_in_group`]: ./struct.App.html#method.args_in_group
+    pub fn groups_for_arg<T: Key>(&self, arg_id: T) -> Vec<&str> {
+        let id = Id::from(arg_id);
+        // As in `App::arg_conflicts`, fall back to hashing the name so this works on an
+        // unbuilt `App` whose arg ids are not resolved yet.
+        let arg = self
+            .args
+            .args()
+            .find(|a| a.id == id || (!a.id_explicit && Id::from(a.name) == id))
+            .expect("App::groups_for_arg: The passed id does not match any argument of the app");
+        self.groups
+            .iter()
+            .filter(|g| g.args.contains(&id) || arg.groups.contains(&g.id))
+            .map(|g| g.name)
+            .collect()
+    }
+
+    /// Get the names of all arguments that are direct members of the named [`ArgGroup`],
+    /// whether the membership was declared on the argument via [`Arg::group`] or on the group
+    /// via [`ArgGroup::arg`]. Groups nested inside the group are not unrolled.
+    ///
+    /// ### Panics
+    ///
+    /// If `group_id` does not match a group of this `App`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::new("json").long("json"))
+    ///     .arg(Arg::new("yaml").long("yaml"))
+    ///     .group(ArgGroup::new("format").args(&["json", "yaml"]));
+    ///
+    /// assert_eq!(app.args_in_group("format"), ["json", "yaml"]);
+    /// ```
+    /// [`ArgGroup`]: ./struct.ArgGroup.html
+    /// [`Arg::group`]: ./struct.Arg.html#method.group
+    pub fn args_in_group<T: Key>(&self, group_id: T) -> Vec<&str> {
+        let id = Id::from(group_id);
+        let group = self
+            .groups
+            .iter()
+            .find(|g| g.id == id)
+            .expect("App::args_in_group: The passed id does not match any group of the app");
+        self.args
+            .args()
+            .filter(|a| {
+                group
+                    .args
+                    .iter()
+                    .any(|gid| a.id == *gid || (!a.id_explicit && Id::from(&*a.name) == *gid))
+                    || a.groups.contains(&id)
+            })
+            .map(|a| a.get_name())
+            .collect()
+    }
+
     /// Returns `true` if the given [`AppSettings`] variant is currently set in
     /// this `App` (checks both [local] and [global settings]).
     ///
@@ -1060,7 +1243,10 @@ impl<'help> App<'help> {
     pub fn arg<A: Into<Arg<'help>>>(mut self, a: A) -> Self {
         let mut arg = a.into();
         if let Some(help_heading) = self.current_help_heading {
-            arg = arg.help_heading(Some(help_heading));
+            // The heading comes from the section the arg was added in, so it counts as
+            // inherited even when it replaces one set on the arg itself
+            arg.help_heading = Some(help_heading.into());
+            arg.help_heading_explicit = false;
         }
         self.args.push(arg);
         self
@@ -1749,7 +1935,7 @@ impl<'help> App<'help> {
         let arg_id: &str = arg_id.into();
         let id = Id::from(arg_id);
 
-        let mut a = self.args.remove_by_name(&id).unwrap_or_else(|| Arg {
+        let mut a = self.args.remove_by_name(arg_id).unwrap_or_else(|| Arg {
             id,
             name: arg_id,
             ..Arg::default()
@@ -2265,6 +2451,11 @@ impl<'help> App<'help> {
     pub fn _build(&mut self) {
         debug!("App::_build");
         if !self.settings.is_set(AppSettings::Built) {
+            // Arg ids are computed lazily; resolve them (including those of direct
+            // subcommands, whose args get compared against ours when propagating
+            // globals) before anything looks at them
+            self._resolve_ids();
+
             // Make sure all the globally set flags apply to us as well
             self.settings = self.settings | self.g_settings;
 
@@ -2272,6 +2463,40 @@ impl<'help> App<'help> {
             self._check_help_and_version();
             self._propagate_global_args();
             self._derive_display_order();
+            self._resolve_relative_display_order();
+
+            // Resolve `overrides_with_everything` now that every sibling arg is known
+            if self.args.args().any(|a| a.overrides_everything) {
+                let all_ids: Vec<Id> = self.args.args().map(|a| a.id.clone()).collect();
+                for a in self.args.args_mut().filter(|a| a.overrides_everything) {
+                    let new_overrides: Vec<Id> = all_ids
+                        .iter()
+                        .filter(|id| **id != a.id && !a.overrides.contains(id))
+                        .cloned()
+                        .collect();
+                    a.overrides.extend(new_overrides);
+                }
+            }
+
+            // Conflicts are symmetric, so reciprocate every declared conflict onto the other
+            // arg's blacklist; `get_arg_conflicts_with` then reports the relationship from
+            // either side. Conflicts naming a group are left to symmetric resolution as before.
+            let declared: Vec<(Id, Id)> = self
+                .args
+                .args()
+                .flat_map(|a| {
+                    a.blacklist
+                        .iter()
+                        .map(move |other| (other.clone(), a.id.clone()))
+                })
+                .collect();
+            for (arg_id, other) in declared {
+                if let Some(arg) = self.args.args_mut().find(|a| a.id == arg_id) {
+                    if !arg.blacklist.contains(&other) {
+                        arg.blacklist.push(other);
+                    }
+                }
+            }
 
             let mut pos_counter = 1;
             for a in self.args.args_mut() {
@@ -2309,6 +2534,13 @@ impl<'help> App<'help> {
         }
     }
 
+    fn _resolve_ids(&mut self) {
+        debug!("App::_resolve_ids");
+        for a in self.args.args_mut() {
+            a._resolve_id();
+        }
+    }
+
     fn _panic_on_missing_help(&self, help_required_globally: bool) {
         if self.is_set(AppSettings::HelpRequired) || help_required_globally {
             let args_missing_help: Vec<String> = self
@@ -2354,6 +2586,10 @@ impl<'help> App<'help> {
         debug!("App::_propagate_global_args:{}", self.name);
 
         for sc in &mut self.subcommands {
+            // The subcommand hasn't been built yet, so make sure its arg ids are
+            // resolved before comparing them against ours
+            sc._resolve_ids();
+
             for a in self.args.args().filter(|a| a.global) {
                 let mut propagate = false;
                 let is_generated = matches!(
@@ -2424,7 +2660,7 @@ impl<'help> App<'help> {
         if self.is_set(AppSettings::DisableHelpFlag)
             || self.args.args().any(|x| {
                 x.provider == ArgProvider::User
-                    && (x.long == Some("help") || x.id == Id::help_hash())
+                    && (x.long.as_deref() == Some("help") || x.id == Id::help_hash())
             })
             || self
                 .subcommands
@@ -2460,7 +2696,7 @@ impl<'help> App<'help> {
         if self.is_set(AppSettings::DisableVersionFlag)
             || self.args.args().any(|x| {
                 x.provider == ArgProvider::User
-                    && (x.long == Some("version") || x.id == Id::version_hash())
+                    && (x.long.as_deref() == Some("version") || x.id == Id::version_hash())
             })
             || self
                 .subcommands
@@ -2532,6 +2768,103 @@ impl<'help> App<'help> {
         }
     }
 
+    pub(crate) fn _resolve_relative_display_order(&mut self) {
+        debug!("App::_resolve_relative_display_order:{}", self.name);
+
+        if !self
+            .args
+            .args()
+            .any(|a| !a.display_before.is_empty() || !a.display_after.is_empty())
+        {
+            return;
+        }
+
+        // Seed with the order help would render today: `display_order` first, then the same
+        // tie-break key `Help::write_args` uses, so args without constraints keep their spot
+        let mut remaining: Vec<Id> = self
+            .args
+            .args()
+            .filter(|a| a.has_switch())
+            .map(|a| a.id.clone())
+            .collect();
+        let sort_key = |a: &Arg| {
+            if let Some(x) = a.short {
+                let mut s = x.to_ascii_lowercase().to_string();
+                s.push(if x.is_ascii_lowercase() { '0' } else { '1' });
+                s
+            } else if let Some(x) = a.long.as_deref() {
+                x.to_string()
+            } else {
+                let mut s = '{'.to_string();
+                s.push_str(a.name);
+                s
+            }
+        };
+        remaining.sort_by_key(|id| {
+            let a = self.args.args().find(|a| a.id == *id).expect(INTERNAL_ERROR_MSG);
+            (a.disp_ord, sort_key(a))
+        });
+
+        // Edges read "left must come before right"
+        let mut edges: Vec<(Id, Id)> = vec![];
+        for a in self.args.args() {
+            // These check programmer errors, so like the checks in debug_asserts.rs they
+            // only run in debug builds
+            #[cfg(debug_assertions)]
+            for &name in a.display_before.iter().chain(a.display_after.iter()) {
+                match self.args.args().find(|other| other.name == name) {
+                    None => panic!(
+                        "Argument '{}' referenced in display_before/display_after of '{}' \
+                         does not exist",
+                        name, a.name
+                    ),
+                    Some(other) => assert!(
+                        other.has_switch(),
+                        "Argument '{}' referenced in display_before/display_after of '{}' \
+                         is positional; positional targets are not supported",
+                        name,
+                        a.name
+                    ),
+                }
+            }
+            for &name in &a.display_before {
+                if let Some(other) = self.args.args().find(|o| o.name == name && o.has_switch()) {
+                    edges.push((a.id.clone(), other.id.clone()));
+                }
+            }
+            for &name in &a.display_after {
+                if let Some(other) = self.args.args().find(|o| o.name == name && o.has_switch()) {
+                    edges.push((other.id.clone(), a.id.clone()));
+                }
+            }
+        }
+
+        // Stable topological sort: always take the earliest seeded arg with no pending
+        // predecessor
+        let mut resolved: Vec<Id> = vec![];
+        while !remaining.is_empty() {
+            let next = remaining
+                .iter()
+                .position(|id| {
+                    !edges
+                        .iter()
+                        .any(|(from, to)| to == id && remaining.contains(from))
+                })
+                .unwrap_or_else(|| {
+                    panic!("Cycle detected in display_before/display_after ordering constraints")
+                });
+            resolved.push(remaining.remove(next));
+        }
+
+        for (i, id) in resolved.iter().enumerate() {
+            self.args
+                .args_mut()
+                .find(|a| a.id == *id)
+                .expect(INTERNAL_ERROR_MSG)
+                .disp_ord = i;
+        }
+    }
+
     // used in clap_generate (https://github.com/clap-rs/clap_generate)
     #[doc(hidden)]
     pub fn _build_bin_names(&mut self) {
@@ -2702,8 +3035,8 @@ impl<'help> App<'help> {
     }
 
     /// Iterate through the groups this arg is member of.
-    pub(crate) fn groups_for_arg<'a>(&'a self, arg: &Id) -> impl Iterator<Item = Id> + 'a {
-        debug!("App::groups_for_arg: id={:?}", arg);
+    pub(crate) fn groups_for_arg_id<'a>(&'a self, arg: &Id) -> impl Iterator<Item = Id> + 'a {
+        debug!("App::groups_for_arg_id: id={:?}", arg);
         let arg = arg.clone();
         self.groups
             .iter()
@@ -2768,19 +3101,22 @@ impl<'help> App<'help> {
     }
 
     pub(crate) fn unroll_requirements_for_arg(&self, arg: &Id, matcher: &ArgMatcher) -> Vec<Id> {
-        let requires_if_or_not = |(val, req_arg): &(Option<&str>, Id)| -> Option<Id> {
-            if let Some(v) = val {
-                if matcher
+        let requires_if_or_not = |(pred, req_arg): &(RequiresPredicate<'help>, Id)| -> Option<Id> {
+            let applies = match pred {
+                RequiresPredicate::Equals(v) => matcher
                     .get(arg)
-                    .map(|ma| ma.contains_val(v))
-                    .unwrap_or(false)
-                {
-                    Some(req_arg.clone())
-                } else {
-                    None
-                }
-            } else {
+                    .map(|ma| ma.contains_val_os(v))
+                    .unwrap_or(false),
+                RequiresPredicate::NotEquals(v) => matcher
+                    .get(arg)
+                    .map(|ma| ma.vals_flatten().any(|val| val.as_os_str() != *v))
+                    .unwrap_or(false),
+                RequiresPredicate::Always => true,
+            };
+            if applies {
                 Some(req_arg.clone())
+            } else {
+                None
             }
         };
 
@@ -48,6 +48,8 @@ bitflags! {
         const HELP_REQUIRED                  = 1 << 40;
         const SUBCOMMAND_PRECEDENCE_OVER_ARG = 1 << 41;
         const DISABLE_HELP_FLAG              = 1 << 42;
+        const AGGREGATE_VALUE_ERRORS         = 1 << 43;
+        const SHOW_HIDDEN_ARGS               = 1 << 44;
     }
 }
 
@@ -147,7 +149,11 @@ impl_settings! { AppSettings, AppFlags,
     InferSubcommands("infersubcommands")
         => Flags::INFER_SUBCOMMANDS,
     AllArgsOverrideSelf("allargsoverrideself")
-        => Flags::ARGS_OVERRIDE_SELF
+        => Flags::ARGS_OVERRIDE_SELF,
+    AggregateValueErrors("aggregatevalueerrors")
+        => Flags::AGGREGATE_VALUE_ERRORS,
+    ShowHiddenArgs("showhiddenargs")
+        => Flags::SHOW_HIDDEN_ARGS
 }
 
 /// Application level settings, which affect how [`App`] operates
@@ -1034,6 +1040,39 @@ pub enum AppSettings {
     /// [``]: ./struct..html
     WaitOnError,
 
+    /// Specifies that value validation errors from *all* arguments should be collected and
+    /// reported together, instead of failing on the first invalid value. This is useful for
+    /// "form style" interfaces where the user should see every invalid value in a single run.
+    ///
+    /// **NOTE:** Only [`Arg::validator`] style value errors are aggregated; other errors
+    /// (conflicts, missing required arguments, etc.) still abort immediately
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings};
+    /// App::new("myprog")
+    ///     .setting(AppSettings::AggregateValueErrors)
+    /// # ;
+    /// ```
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    AggregateValueErrors,
+
+    /// Long help (`--help`) additionally lists arguments marked with
+    /// [`Arg::hide_unless_long_help`], which are otherwise left out of both help variants.
+    /// Useful behind a debug flag to expose internal options on demand.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings};
+    /// App::new("myprog")
+    ///     .setting(AppSettings::ShowHiddenArgs)
+    /// # ;
+    /// ```
+    /// [`Arg::hide_unless_long_help`]: ./struct.Arg.html#method.hide_unless_long_help
+    ShowHiddenArgs,
+
     /// @TODO-v3: @docs write them...maybe rename
     NoAutoHelp,
 
@@ -1192,6 +1231,10 @@ mod test {
             "waitonerror".parse::<AppSettings>().unwrap(),
             AppSettings::WaitOnError
         );
+        assert_eq!(
+            "aggregatevalueerrors".parse::<AppSettings>().unwrap(),
+            AppSettings::AggregateValueErrors
+        );
         assert_eq!(
             "validargfound".parse::<AppSettings>().unwrap(),
             AppSettings::ValidArgFound
@@ -1209,6 +1252,10 @@ mod test {
             "infersubcommands".parse::<AppSettings>().unwrap(),
             AppSettings::InferSubcommands
         );
+        assert_eq!(
+            "showhiddenargs".parse::<AppSettings>().unwrap(),
+            AppSettings::ShowHiddenArgs
+        );
         assert!("hahahaha".parse::<AppSettings>().is_err());
     }
 }
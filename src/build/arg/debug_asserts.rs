@@ -43,6 +43,19 @@ pub(crate) fn assert_arg(arg: &Arg) {
         );
     }
 
+    if let Some(num_vals) = arg.num_vals {
+        // value_labels are display-only and deliberately carry no count
+        if !arg.val_labels && arg.val_names.len() > 1 {
+            assert!(
+                arg.val_names.len() == num_vals,
+                "Argument '{}' has {} value names but number_of_values({}); the counts must match",
+                arg.name,
+                arg.val_names.len(),
+                num_vals
+            );
+        }
+    }
+
     assert_app_flags(arg);
 }
 
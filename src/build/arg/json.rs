@@ -0,0 +1,114 @@
+use serde_json::Value;
+
+/// Validates `value` against a (subset of a) JSON schema fragment.
+///
+/// The supported keywords are `type`, `enum`, `required`, `properties`, and `items`, which is
+/// enough for the structural validation command line arguments usually need. Errors cite the
+/// path of the failing field.
+pub(crate) fn validate_fragment(schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    let display_path = |p: &str| {
+        if p.is_empty() {
+            String::from("the document")
+        } else {
+            format!("'{}'", p)
+        }
+    };
+
+    if let Some(ty) = schema.get("type").and_then(Value::as_str) {
+        let matches = match ty {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "number" => value.is_number(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "boolean" => value.is_boolean(),
+            "null" => value.is_null(),
+            _ => true,
+        };
+        if !matches {
+            return Err(format!("{} is not of type '{}'", display_path(path), ty));
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            return Err(format!(
+                "{} is not one of the allowed values",
+                display_path(path)
+            ));
+        }
+    }
+
+    if let Some(required) = schema.get("required").and_then(Value::as_array) {
+        if let Some(obj) = value.as_object() {
+            for req in required.iter().filter_map(Value::as_str) {
+                if !obj.contains_key(req) {
+                    let field = if path.is_empty() {
+                        req.to_string()
+                    } else {
+                        format!("{}.{}", path, req)
+                    };
+                    return Err(format!("missing required field '{}'", field));
+                }
+            }
+        }
+    }
+
+    if let Some(props) = schema.get("properties").and_then(Value::as_object) {
+        if let Some(obj) = value.as_object() {
+            for (name, prop_schema) in props {
+                if let Some(prop_value) = obj.get(name) {
+                    let field = if path.is_empty() {
+                        name.to_string()
+                    } else {
+                        format!("{}.{}", path, name)
+                    };
+                    validate_fragment(prop_schema, prop_value, &field)?;
+                }
+            }
+        }
+    }
+
+    if let Some(item_schema) = schema.get("items") {
+        if let Some(items) = value.as_array() {
+            for (i, item) in items.iter().enumerate() {
+                let field = format!("{}[{}]", path, i);
+                validate_fragment(item_schema, item, &field)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn type_mismatch_cites_field() {
+        let schema: Value = serde_json::from_str(r#"{"properties": {"k": {"type": "number"}}}"#)
+            .unwrap();
+        let value: Value = serde_json::from_str(r#"{"k": "nope"}"#).unwrap();
+        let err = validate_fragment(&schema, &value, "").unwrap_err();
+        assert!(err.contains("'k'"), "{}", err);
+    }
+
+    #[test]
+    fn missing_required_field() {
+        let schema: Value = serde_json::from_str(r#"{"required": ["k"]}"#).unwrap();
+        let value: Value = serde_json::from_str("{}").unwrap();
+        let err = validate_fragment(&schema, &value, "").unwrap_err();
+        assert!(err.contains("'k'"), "{}", err);
+    }
+
+    #[test]
+    fn valid_document() {
+        let schema: Value = serde_json::from_str(
+            r#"{"type": "object", "required": ["k"], "properties": {"k": {"type": "integer"}}}"#,
+        )
+        .unwrap();
+        let value: Value = serde_json::from_str(r#"{"k": 1}"#).unwrap();
+        assert!(validate_fragment(&schema, &value, "").is_ok());
+    }
+}
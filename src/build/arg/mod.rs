@@ -30,6 +30,9 @@ mod regex;
 #[cfg(feature = "regex")]
 pub use self::regex::RegexRef;
 
+#[cfg(feature = "json")]
+mod json;
+
 // Internal
 use crate::{
     build::{arg::settings::ArgFlags, usage_parser::UsageParser},
@@ -43,6 +46,18 @@ use yaml_rust::Yaml;
 
 type Validator<'a> = dyn FnMut(&str) -> Result<(), Box<dyn Error + Send + Sync>> + Send + 'a;
 type ValidatorOs<'a> = dyn FnMut(&OsStr) -> Result<(), Box<dyn Error + Send + Sync>> + Send + 'a;
+type ValidatorAll<'a> =
+    dyn FnMut(&[&str]) -> Result<(), Box<dyn Error + Send + Sync>> + Send + 'a;
+type ValidatorNamed<'a> =
+    dyn FnMut(&str, &str) -> Result<(), Box<dyn Error + Send + Sync>> + Send + 'a;
+type ValidatorWarn<'a> = dyn FnMut(&str) -> Option<String> + Send + 'a;
+type ValidatorMatches<'a> =
+    dyn FnMut(&str, &crate::ArgMatches) -> Result<(), Box<dyn Error + Send + Sync>> + Send + 'a;
+type ValueMapper<'a> = dyn FnMut(Vec<String>) -> Vec<String> + Send + 'a;
+type ValueMapperOs<'a> =
+    dyn FnMut(&OsStr) -> Result<OsString, Box<dyn Error + Send + Sync>> + Send + 'a;
+type ValidatorSuggestions<'a> =
+    dyn FnMut(&str) -> Result<(), (String, Vec<String>)> + Send + 'a;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum ArgProvider {
@@ -57,6 +72,30 @@ impl Default for ArgProvider {
     }
 }
 
+/// The condition attached to a single `requires` entry: whether the target is required
+/// unconditionally, or only when this argument's value does (or does not) equal the carried
+/// value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RequiresPredicate<'help> {
+    Always,
+    Equals(&'help OsStr),
+    NotEquals(&'help OsStr),
+}
+
+/// The condition under which a conditional default value applies, used with
+/// [`Arg::default_values_from`]. Each variant carries the default to use when the condition
+/// holds for the paired argument.
+///
+/// [`Arg::default_values_from`]: ./struct.Arg.html#method.default_values_from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultWhen<'help> {
+    /// Use the carried default whenever the paired argument is present at runtime.
+    Present(&'help str),
+    /// Use the carried default (second string) when the paired argument equals the given value
+    /// (first string) at runtime.
+    Equals(&'help str, &'help str),
+}
+
 /// The abstract representation of a command line argument. Used to set all the options and
 /// relationships that define a valid argument for the program.
 ///
@@ -78,6 +117,15 @@ impl Default for ArgProvider {
 /// // Using a usage string (setting a similar argument to the one above)
 /// let input = Arg::from("-i, --input=[FILE] 'Provides an input file to the program'");
 /// ```
+///
+/// In usage strings a value name may be followed by a delimiter token in braces, declaring the
+/// character used to split a single occurrence into multiple values:
+///
+/// ```rust
+/// # use clap::Arg;
+/// // --list takes comma separated values, e.g. `--list a,b,c`
+/// let list = Arg::from("--list=[ITEM]{,} 'Provides a list of items'");
+/// ```
 /// [`Arg`]: ./struct.Arg.html
 #[allow(missing_debug_implementations)]
 #[derive(Default, Clone)]
@@ -85,37 +133,89 @@ pub struct Arg<'help> {
     pub(crate) id: Id,
     pub(crate) provider: ArgProvider,
     pub(crate) name: &'help str,
-    pub(crate) about: Option<&'help str>,
-    pub(crate) long_about: Option<&'help str>,
+    pub(crate) about: Option<Cow<'help, str>>,
+    pub(crate) long_about: Option<Cow<'help, str>>,
     pub(crate) blacklist: Vec<Id>,
+    pub(crate) blacklisted_subcommands: Vec<&'help str>,
     pub(crate) settings: ArgFlags,
     pub(crate) overrides: Vec<Id>,
+    pub(crate) overrides_everything: bool,
+    pub(crate) allow_self_override: bool,
     pub(crate) groups: Vec<Id>,
-    pub(crate) requires: Vec<(Option<&'help str>, Id)>,
+    pub(crate) requires: Vec<(RequiresPredicate<'help>, Id)>,
+    pub(crate) requires_explicit: bool,
     pub(crate) r_ifs: Vec<(Id, &'help str)>,
     pub(crate) r_ifs_all: Vec<(Id, &'help str)>,
+    pub(crate) r_if_groups: Vec<Id>,
     pub(crate) r_unless: Vec<Id>,
     pub(crate) short: Option<char>,
-    pub(crate) long: Option<&'help str>,
+    pub(crate) long: Option<Cow<'help, str>>,
     pub(crate) aliases: Vec<(&'help str, bool)>, // (name, visible)
     pub(crate) short_aliases: Vec<(char, bool)>, // (name, visible)
+    pub(crate) short_case_insensitive: bool,
     pub(crate) disp_ord: usize,
+    pub(crate) display_before: Vec<&'help str>,
+    pub(crate) display_after: Vec<&'help str>,
     pub(crate) unified_ord: usize,
     pub(crate) possible_vals: Vec<&'help str>,
+    pub(crate) possible_val_aliases: Vec<(&'help str, &'help str)>, // (alias, canonical)
+    pub(crate) possible_vals_help: Vec<(&'help str, &'help str)>,   // (value, help)
+    pub(crate) normalize_case: bool,
+    pub(crate) show_pv_aliases: bool,
+    pub(crate) value_name_case_by_kind: bool,
+    pub(crate) auto_uppercase_value_name: bool,
+    pub(crate) hide_default_if: Option<Id>,
+    pub(crate) values_from_lines: bool,
+    pub(crate) validation_exit_code: Option<i32>,
+    pub(crate) expand_at_files: bool,
+    pub(crate) interpolate_help: bool,
+    pub(crate) max_occurs: Option<u64>,
+    pub(crate) deprecated: Option<&'help str>,
+    pub(crate) quoted_delimiters: bool,
+    pub(crate) balanced_delimiters: bool,
+    pub(crate) id_explicit: bool,
+    #[cfg(feature = "prompt")]
+    pub(crate) prompt_if_missing: Option<&'help str>,
+    #[cfg(feature = "tty_default")]
+    pub(crate) default_val_if_tty: Option<(&'help OsStr, fn() -> bool)>,
     pub(crate) val_names: VecMap<&'help str>,
+    pub(crate) val_labels: bool,
+    pub(crate) val_range: Option<(i64, i64)>,
     pub(crate) num_vals: Option<usize>,
     pub(crate) max_vals: Option<usize>,
     pub(crate) min_vals: Option<usize>,
     pub(crate) validator: Option<Arc<Mutex<Validator<'help>>>>,
     pub(crate) validator_os: Option<Arc<Mutex<ValidatorOs<'help>>>>,
-    pub(crate) val_delim: Option<char>,
+    pub(crate) validator_all: Option<Arc<Mutex<ValidatorAll<'help>>>>,
+    pub(crate) validator_suggestions: Option<Arc<Mutex<ValidatorSuggestions<'help>>>>,
+    pub(crate) validator_named: Option<Arc<Mutex<ValidatorNamed<'help>>>>,
+    pub(crate) validator_warn: Option<Arc<Mutex<ValidatorWarn<'help>>>>,
+    pub(crate) validator_matches: Option<Arc<Mutex<ValidatorMatches<'help>>>>,
+    pub(crate) validate_before_possible_vals: bool,
+    pub(crate) validate_last_only: bool,
+    pub(crate) value_mapper: Option<Arc<Mutex<ValueMapper<'help>>>>,
+    pub(crate) value_mapper_os: Option<Arc<Mutex<ValueMapperOs<'help>>>>,
+    pub(crate) val_delim: Option<&'help str>,
+    pub(crate) key_val_delim: Option<char>,
     pub(crate) default_vals: Vec<&'help OsStr>,
+    pub(crate) default_val_from: Option<Id>,
+    pub(crate) default_val_from_count: Option<Id>,
     pub(crate) default_vals_ifs: VecMap<(Id, Option<&'help OsStr>, &'help OsStr)>,
     pub(crate) default_missing_vals: Vec<&'help OsStr>,
     pub(crate) env: Option<(&'help OsStr, Option<OsString>)>,
+    pub(crate) env_line_delim: bool,
     pub(crate) terminator: Option<&'help str>,
+    pub(crate) keep_terminator: bool,
+    pub(crate) last_sep: Option<&'help str>,
     pub(crate) index: Option<usize>,
-    pub(crate) help_heading: Option<&'help str>,
+    pub(crate) help_heading: Option<Cow<'help, str>>,
+    pub(crate) help_heading_explicit: bool,
+    pub(crate) uppercase_help_heading: bool,
+    pub(crate) help_heading_order: Option<usize>,
+    pub(crate) hidden_unless_env: Option<&'help str>,
+    pub(crate) hide_pv_unless_env: Option<&'help str>,
+    pub(crate) hide_unless_long_help: bool,
+    pub(crate) value_unit: Option<&'help str>,
     pub(crate) global: bool,
     pub(crate) exclusive: bool,
     pub(crate) value_hint: ValueHint,
@@ -132,7 +232,7 @@ impl<'help> Arg<'help> {
     /// Get the help specified for this argument, if any
     #[inline]
     pub fn get_about(&self) -> Option<&str> {
-        self.about
+        self.about.as_deref()
     }
 
     /// Get the long help specified for this argument, if any
@@ -147,13 +247,133 @@ impl<'help> Arg<'help> {
     ///
     #[inline]
     pub fn get_long_about(&self) -> Option<&str> {
-        self.long_about
+        self.long_about.as_deref()
+    }
+
+    /// Get the help used for short help (`-h`): the [`Arg::about`] text, falling back to
+    /// [`Arg::long_about`] when no short help was given. This is the same fallback clap's own
+    /// help renderer applies.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// let arg = Arg::new("foo").long_about("long about");
+    /// assert_eq!(Some("long about"), arg.get_about_or_long_about());
+    /// ```
+    ///
+    /// [`Arg::about`]: ./struct.Arg.html#method.about
+    /// [`Arg::long_about`]: ./struct.Arg.html#method.long_about
+    #[inline]
+    pub fn get_about_or_long_about(&self) -> Option<&str> {
+        self.about.as_deref().or(self.long_about.as_deref())
+    }
+
+    /// Get the help used for long help (`--help`): the [`Arg::long_about`] text, falling back
+    /// to [`Arg::about`] when no long help was given. This is the same fallback clap's own
+    /// help renderer applies.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// let arg = Arg::new("foo").about("short about");
+    /// assert_eq!(Some("short about"), arg.get_long_about_or_about());
+    /// ```
+    ///
+    /// [`Arg::about`]: ./struct.Arg.html#method.about
+    /// [`Arg::long_about`]: ./struct.Arg.html#method.long_about
+    #[inline]
+    pub fn get_long_about_or_about(&self) -> Option<&str> {
+        self.long_about.as_deref().or(self.about.as_deref())
     }
 
-    /// Get the help heading specified for this argument, if any
+    /// Get the help heading explicitly set on this argument via [`Arg::help_heading`], if any.
+    /// Headings inherited from [`App::help_heading`] are not reported here; use
+    /// [`Arg::get_effective_help_heading`] for the heading the argument is grouped under.
+    ///
+    /// [`Arg::help_heading`]: ./struct.Arg.html#method.help_heading
+    /// [`App::help_heading`]: ./struct.App.html#method.help_heading
+    /// [`Arg::get_effective_help_heading`]: ./struct.Arg.html#method.get_effective_help_heading
     #[inline]
     pub fn get_help_heading(&self) -> Option<&str> {
-        self.help_heading
+        if self.help_heading_explicit {
+            self.help_heading.as_deref()
+        } else {
+            None
+        }
+    }
+
+    /// Get the help heading this argument is grouped under after inheritance: an explicit
+    /// heading wins, then one inherited from [`App::help_heading`], then `app_default`
+    ///
+    /// [`App::help_heading`]: ./struct.App.html#method.help_heading
+    #[inline]
+    pub fn get_effective_help_heading<'a>(&'a self, app_default: Option<&'a str>) -> Option<&'a str> {
+        self.help_heading.as_deref().or(app_default)
+    }
+
+    /// Get the help heading specified for this argument normalized according to
+    /// [`Arg::uppercase_help_heading`], if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// let arg = Arg::new("foo")
+    ///     .help_heading(Some("Network"))
+    ///     .uppercase_help_heading(true);
+    /// assert_eq!(Some("NETWORK"), arg.get_help_heading_normalized().as_deref());
+    /// ```
+    ///
+    /// [`Arg::uppercase_help_heading`]: ./struct.Arg.html#method.uppercase_help_heading
+    #[inline]
+    pub fn get_help_heading_normalized(&self) -> Option<Cow<'help, str>> {
+        self.help_heading.as_ref().map(|heading| {
+            if self.uppercase_help_heading {
+                Cow::Owned(heading.to_uppercase())
+            } else {
+                heading.clone()
+            }
+        })
+    }
+
+    /// Get the deprecation message set via [`Arg::deprecated`], if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// let arg = Arg::new("foo").deprecated("use '--bar' instead");
+    /// assert_eq!(Some("use '--bar' instead"), arg.get_deprecated());
+    /// ```
+    ///
+    /// [`Arg::deprecated`]: ./struct.Arg.html#method.deprecated
+    #[inline]
+    pub fn get_deprecated(&self) -> Option<&str> {
+        self.deprecated
+    }
+
+    /// Report whether a custom validator is attached to this argument via [`Arg::validator`]
+    /// or [`Arg::validator_os`]. The closure itself cannot be introspected, but schema
+    /// exporters can mark the field as custom-validated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// let arg = Arg::new("port").validator(|v: &str| v.parse::<u16>().map_err(|e| e.to_string()));
+    /// assert!(arg.has_validator());
+    ///
+    /// let arg = Arg::new("port");
+    /// assert!(!arg.has_validator());
+    /// ```
+    ///
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    /// [`Arg::validator_os`]: ./struct.Arg.html#method.validator_os
+    #[inline]
+    pub fn has_validator(&self) -> bool {
+        self.validator.is_some() || self.validator_os.is_some()
     }
 
     /// Get the short option name for this argument, if any
@@ -191,10 +411,21 @@ impl<'help> Arg<'help> {
         Some(shorts)
     }
 
+    /// Get every single-char trigger for this argument: the short option name (if any) followed
+    /// by all short aliases, visible or not
+    #[inline]
+    pub fn get_all_short_flags(&self) -> Vec<char> {
+        self.short
+            .iter()
+            .copied()
+            .chain(self.short_aliases.iter().map(|(c, _)| *c))
+            .collect()
+    }
+
     /// Get the long option name for this argument, if any
     #[inline]
     pub fn get_long(&self) -> Option<&str> {
-        self.long
+        self.long.as_deref()
     }
 
     /// Get visible aliases for this argument, if any
@@ -216,7 +447,7 @@ impl<'help> Arg<'help> {
     /// Get the long option name and its visible aliases, if any
     #[inline]
     pub fn get_long_and_visible_aliases(&self) -> Option<Vec<&str>> {
-        let mut longs = match self.long {
+        let mut longs = match self.long.as_deref() {
             Some(long) => vec![long],
             None => return None,
         };
@@ -226,6 +457,38 @@ impl<'help> Arg<'help> {
         Some(longs)
     }
 
+    /// Get the value terminator set via [`Arg::value_terminator`], if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// let arg = Arg::new("vals").value_terminator(";");
+    /// assert_eq!(Some(";"), arg.get_value_terminator());
+    /// ```
+    /// [`Arg::value_terminator`]: ./struct.Arg.html#method.value_terminator
+    #[inline]
+    pub fn get_value_terminator(&self) -> Option<&str> {
+        self.terminator
+    }
+
+    /// Get the value delimiter set via [`Arg::value_delimiter`] or
+    /// [`Arg::use_delimiter`], if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// let arg = Arg::new("vals").value_delimiter(";");
+    /// assert_eq!(Some(";"), arg.get_value_delimiter());
+    /// ```
+    /// [`Arg::value_delimiter`]: ./struct.Arg.html#method.value_delimiter
+    /// [`Arg::use_delimiter`]: ./struct.Arg.html#method.use_delimiter
+    #[inline]
+    pub fn get_value_delimiter(&self) -> Option<&str> {
+        self.val_delim
+    }
+
     /// Get the list of the possible values for this argument, if any
     #[inline]
     pub fn get_possible_values(&self) -> Option<&[&str]> {
@@ -236,6 +499,51 @@ impl<'help> Arg<'help> {
         }
     }
 
+    /// Get the possible value/help pairs registered via [`Arg::possible_value_with_help`], if
+    /// any. Values added through the plain [`Arg::possible_value`] family are not included;
+    /// use [`Arg::get_possible_values`] for those.
+    ///
+    /// [`Arg::possible_value_with_help`]: ./struct.Arg.html#method.possible_value_with_help
+    /// [`Arg::possible_value`]: ./struct.Arg.html#method.possible_value
+    /// [`Arg::get_possible_values`]: ./struct.Arg.html#method.get_possible_values
+    #[inline]
+    pub fn get_possible_values_help(&self) -> Option<&[(&str, &str)]> {
+        if self.possible_vals_help.is_empty() {
+            None
+        } else {
+            Some(&self.possible_vals_help)
+        }
+    }
+
+    /// Get every [`ArgSettings`] currently active on this argument, in the declaration order
+    /// of the enum. Convenient for debugging and snapshot tests over checking
+    /// [`Arg::is_set`] one variant at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{Arg, ArgSettings};
+    /// let arg = Arg::new("config").required(true).takes_value(true);
+    /// assert_eq!(arg.get_settings(), vec![ArgSettings::Required, ArgSettings::TakesValue]);
+    /// ```
+    /// [`ArgSettings`]: ./enum.ArgSettings.html
+    /// [`Arg::is_set`]: ./struct.Arg.html#method.is_set
+    pub fn get_settings(&self) -> Vec<ArgSettings> {
+        ArgSettings::ALL
+            .iter()
+            .copied()
+            .filter(|s| self.is_set(*s))
+            .collect()
+    }
+
+    /// Get the unit hint set via [`Arg::value_unit`], if any
+    ///
+    /// [`Arg::value_unit`]: ./struct.Arg.html#method.value_unit
+    #[inline]
+    pub fn get_value_unit(&self) -> Option<&str> {
+        self.value_unit
+    }
+
     /// Get the index of this argument, if any
     #[inline]
     pub fn get_index(&self) -> Option<usize> {
@@ -278,6 +586,48 @@ impl<'help> Arg<'help> {
     pub fn get_default_values(&self) -> &[&OsStr] {
         &self.default_vals
     }
+
+    /// Get the value names specified for this argument, in index order, if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// let arg = Arg::new("file").value_names(&["INFILE", "OUTFILE"]);
+    /// assert_eq!(Some(vec!["INFILE", "OUTFILE"]), arg.get_value_names());
+    ///
+    /// let arg = Arg::new("file");
+    /// assert_eq!(None, arg.get_value_names());
+    /// ```
+    pub fn get_value_names(&self) -> Option<Vec<&str>> {
+        if self.val_names.is_empty() {
+            None
+        } else {
+            Some(self.val_names.values().copied().collect())
+        }
+    }
+
+    /// Get the number of values this argument expects, either set explicitly via
+    /// [`Arg::number_of_values`] or derived from the number of [`Arg::value_names`], if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// let arg = Arg::new("file").value_names(&["INFILE", "OUTFILE"]);
+    /// assert_eq!(Some(2), arg.get_number_of_values());
+    /// ```
+    /// [`Arg::number_of_values`]: ./struct.Arg.html#method.number_of_values
+    /// [`Arg::value_names`]: ./struct.Arg.html#method.value_names
+    pub fn get_number_of_values(&self) -> Option<usize> {
+        self.num_vals.or_else(|| {
+            if !self.val_labels && self.val_names.len() > 1 {
+                Some(self.val_names.len())
+            } else {
+                None
+            }
+        })
+    }
 }
 
 impl<'help> Arg<'help> {
@@ -299,16 +649,70 @@ impl<'help> Arg<'help> {
     /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
     /// [`Arg`]: ./struct.Arg.html
     pub fn new<S: Into<&'help str>>(n: S) -> Self {
-        let name = n.into();
         Arg {
-            id: Id::from(&*name),
-            name,
+            name: n.into(),
             disp_ord: 999,
             unified_ord: 999,
             ..Default::default()
         }
     }
 
+    /// Creates a simple boolean flag in one call: sugar for
+    /// `Arg::new(name).short(short).long(long)`. The arg takes no value, is not positional
+    /// and may appear at most once unless [`Arg::multiple_occurrences`] is set afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::flag("verbose", 'v', "verbose"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--verbose"
+    ///     ]);
+    ///
+    /// assert!(m.is_present("verbose"));
+    /// ```
+    /// [`Arg::multiple_occurrences`]: ./struct.Arg.html#method.multiple_occurrences
+    pub fn flag<S: Into<&'help str>>(n: S, short: char, long: &'help str) -> Self {
+        Arg::new(n).short(short).long(long)
+    }
+
+    // Computing the id hashes the name, which adds up for apps with hundreds of args, so
+    // `Arg::new` defers it until the `App` is built. The id is derived from the name unless
+    // `Arg::with_id` decoupled the two.
+    pub(crate) fn _resolve_id(&mut self) {
+        if !self.id_explicit {
+            self.id = Id::from(self.name);
+        }
+    }
+
+    /// Sets the lookup id of the argument independently of its display name. By default the id
+    /// is derived from the name given to [`Arg::new`]; this decouples the two, so two args can
+    /// share a display name (e.g. under different subcommands) while [`ArgMatches`] lookups use
+    /// distinct keys. [`Arg::get_name`] keeps returning the display name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("NUM").with_id("port"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "8080",
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("port"), Some("8080"));
+    /// ```
+    /// [`Arg::new`]: ./struct.Arg.html#method.new
+    /// [`ArgMatches`]: ./struct.ArgMatches.html
+    /// [`Arg::get_name`]: ./struct.Arg.html#method.get_name
+    pub fn with_id<T: Key>(mut self, id: T) -> Self {
+        self.id = Id::from(id);
+        self.id_explicit = true;
+        self
+    }
+
     pub(crate) fn generated(mut self) -> Self {
         self.provider = ArgProvider::Generated;
         self
@@ -346,7 +750,24 @@ impl<'help> Arg<'help> {
     ///
     /// assert!(m.is_present("config"));
     /// ```
+    ///
+    /// A digit such as `'1'` is a valid short. `-1` is then parsed as that flag, *unless*
+    /// [`AppSettings::AllowNegativeNumbers`] is set, in which case anything that looks like a
+    /// number is preferred as a value for a value-taking argument
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("one")
+    ///         .short('1'))
+    ///     .get_matches_from(vec![
+    ///         "prog", "-1"
+    ///     ]);
+    ///
+    /// assert!(m.is_present("one"));
+    /// ```
     /// [`short`]: ./struct.Arg.html#method.short
+    /// [`AppSettings::AllowNegativeNumbers`]: ./enum.AppSettings.html#variant.AllowNegativeNumbers
     #[inline]
     pub fn short(mut self, s: char) -> Self {
         if s == '-' {
@@ -357,6 +778,36 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// Sets several equivalent primary short versions at once: the first char becomes the
+    /// canonical [`Arg::short`] and the remainder become *visible* short aliases, so all of
+    /// them show up in help (unlike hidden [`Arg::short_aliases`]). Useful for legacy tools
+    /// where e.g. `-?` and `-h` are truly interchangeable.
+    ///
+    /// # Panics
+    ///
+    /// If `shorts` is empty, or any of the chars is `-`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("query")
+    ///         .shorts(&['?', 'q']))
+    ///     .get_matches_from(vec![
+    ///         "prog", "-?"
+    ///     ]);
+    /// assert!(m.is_present("query"));
+    /// ```
+    /// [`Arg::short`]: ./struct.Arg.html#method.short
+    /// [`Arg::short_aliases`]: ./struct.Arg.html#method.short_aliases
+    pub fn shorts(self, shorts: &[char]) -> Self {
+        let (first, rest) = shorts
+            .split_first()
+            .expect("Arg::shorts requires at least one short option name");
+        self.short(*first).visible_short_aliases(rest)
+    }
+
     /// Sets the long version of the argument without the preceding `--`.
     ///
     /// By default `clap` automatically assigns `version` and `help` to the auto-generated
@@ -392,9 +843,60 @@ impl<'help> Arg<'help> {
     ///
     /// assert!(m.is_present("cfg"));
     /// ```
+    ///
+    /// Owned strings are accepted too, which allows long names generated at runtime:
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let name = format!("{}-file", "config");
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("cfg")
+    ///         .long(name))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--config-file"
+    ///     ]);
+    ///
+    /// assert!(m.is_present("cfg"));
+    /// ```
+    #[inline]
+    pub fn long<L: Into<Cow<'help, str>>>(mut self, l: L) -> Self {
+        self.long = Some(match l.into() {
+            Cow::Borrowed(l) => Cow::Borrowed(l.trim_start_matches('-')),
+            Cow::Owned(mut l) => {
+                let trimmed = l.trim_start_matches('-').len();
+                l.replace_range(..l.len() - trimmed, "");
+                Cow::Owned(l)
+            }
+        });
+        self
+    }
+
+    /// Sets the long version of the argument and derives the short version from it, for the
+    /// common case of a short that matches the long (`-c`/`--config`). The short is the first
+    /// UTF-8 character of the long after any leading `-` characters have been stripped, exactly
+    /// as if it had been passed to [`Arg::short`].
+    ///
+    /// **NOTE:** Any leading `-` characters will be stripped
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("cfg")
+    ///         .long_short("config"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "-c"
+    ///     ]);
+    ///
+    /// assert!(m.is_present("cfg"));
+    /// ```
+    /// [`Arg::short`]: ./struct.Arg.html#method.short
     #[inline]
-    pub fn long(mut self, l: &'help str) -> Self {
-        self.long = Some(l.trim_start_matches(|c| c == '-'));
+    pub fn long_short(mut self, l: &'help str) -> Self {
+        let l = l.trim_start_matches('-');
+        self.long = Some(Cow::Borrowed(l));
+        self.short = l.chars().next();
         self
     }
 
@@ -454,6 +956,30 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// When set, the opposite-case form of this argument's short is registered as a hidden
+    /// short alias at build time, so e.g. `-F` also matches `-f`. Only alphabetic shorts are
+    /// affected; digit or symbol shorts have no opposite case and are left alone. Useful for
+    /// tools migrating from a case-insensitive legacy parser.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///             .arg(Arg::new("force")
+    ///             .short('F')
+    ///             .short_case_insensitive(true))
+    ///        .get_matches_from(vec![
+    ///             "prog", "-f"
+    ///         ]);
+    /// assert!(m.is_present("force"));
+    /// ```
+    #[inline]
+    pub fn short_case_insensitive(mut self, ci: bool) -> Self {
+        self.short_case_insensitive = ci;
+        self
+    }
+
     /// Allows adding [`Arg`] aliases, which function as "hidden" arguments that
     /// automatically dispatch as if this argument was used. This is more efficient, and easier
     /// than creating multiple hidden subcommands as one only needs to check for the existence of
@@ -665,10 +1191,20 @@ impl<'help> Arg<'help> {
     /// -h, --help       Prints help information
     /// -V, --version    Prints version information
     /// ```
+    /// Owned strings are accepted too, which allows help text generated at runtime (for
+    /// example loaded from a message catalog):
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let text = format!("The config file used by {}", "myprog");
+    /// Arg::new("config")
+    ///     .about(text)
+    /// # ;
+    /// ```
     /// [`Arg::long_about`]: ./struct.Arg.html#method.long_about
     #[inline]
-    pub fn about(mut self, h: &'help str) -> Self {
-        self.about = Some(h);
+    pub fn about<S: Into<Cow<'help, str>>>(mut self, h: S) -> Self {
+        self.about = Some(h.into());
         self
     }
 
@@ -737,17 +1273,67 @@ impl<'help> Arg<'help> {
     ///     -V, --version
     ///             Prints version information
     /// ```
+    /// Like [`Arg::about`], owned strings are accepted for help text generated at runtime.
+    ///
     /// [`Arg::about`]: ./struct.Arg.html#method.about
     #[inline]
-    pub fn long_about(mut self, h: &'help str) -> Self {
-        self.long_about = Some(h);
+    pub fn long_about<S: Into<Cow<'help, str>>>(mut self, h: S) -> Self {
+        self.long_about = Some(h.into());
         self
     }
 
-    /// Set this arg as [required] as long as the specified argument is not present at runtime.
-    ///
-    /// **Pro Tip:** Using `Arg::required_unless_present` implies [`Arg::required`] and is therefore not
-    /// mandatory to also set.
+    /// Specifies that `{bin}` tokens in this argument's [`Arg::about`] and [`Arg::long_about`]
+    /// are replaced by the binary name when help is rendered, making reusable help snippets
+    /// portable across renamed binaries. A doubled `{{bin}}` escapes to a literal `{bin}`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// App::new("prog")
+    ///     .arg(Arg::new("cfg")
+    ///         .long("config")
+    ///         .about("Where {bin} looks for its configuration")
+    ///         .interpolate_help(true))
+    /// # ;
+    /// ```
+    /// [`Arg::about`]: ./struct.Arg.html#method.about
+    /// [`Arg::long_about`]: ./struct.Arg.html#method.long_about
+    #[inline]
+    pub fn interpolate_help(mut self, b: bool) -> Self {
+        self.interpolate_help = b;
+        self
+    }
+
+    /// Marks this argument as deprecated with the given message. The argument keeps working
+    /// exactly as before — it still matches and stores values — but when it is actually used on
+    /// the command line the message is printed to `stderr` once, so users can migrate at their
+    /// own pace without the parse failing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("color")
+    ///         .long("colour")
+    ///         .deprecated("'--colour' is deprecated, use '--color' instead"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--colour"
+    ///     ]);
+    ///
+    /// assert!(m.is_present("color"));
+    /// ```
+    #[inline]
+    pub fn deprecated(mut self, msg: &'help str) -> Self {
+        self.deprecated = Some(msg);
+        self
+    }
+
+    /// Set this arg as [required] as long as the specified argument is not present at runtime.
+    ///
+    /// **Pro Tip:** Using `Arg::required_unless_present` implies [`Arg::required`] and is therefore not
+    /// mandatory to also set.
     ///
     /// # Examples
     ///
@@ -965,6 +1551,9 @@ impl<'help> Arg<'help> {
     ///
     /// **NOTE** [`Arg::exclusive(true)`] allows specifying an argument which conflicts with every other argument.
     ///
+    /// **NOTE:** An [`ArgGroup`] id is also accepted: the conflict then applies to the group as
+    /// a whole, so using this argument together with *any* member of the group is an error.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -974,6 +1563,25 @@ impl<'help> Arg<'help> {
     /// # ;
     /// ```
     ///
+    /// Conflicting with a group errs when any group member is present.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("quiet")
+    ///         .conflicts_with("output")
+    ///         .long("quiet"))
+    ///     .arg(Arg::new("json").long("json"))
+    ///     .arg(Arg::new("yaml").long("yaml"))
+    ///     .group(ArgGroup::new("output").args(&["json", "yaml"]))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--quiet", "--json"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::ArgumentConflict);
+    /// ```
+    ///
     /// Setting conflicting argument, and having both arguments present at runtime is an error.
     ///
     /// ```rust
@@ -995,6 +1603,7 @@ impl<'help> Arg<'help> {
     ///
     /// [`Arg::conflicts_with_all(names)`]: ./struct.Arg.html#method.conflicts_with_all
     /// [`Arg::exclusive(true)`]: ./struct.Arg.html#method.exclusive
+    /// [`ArgGroup`]: ./struct.ArgGroup.html
     pub fn conflicts_with<T: Key>(mut self, arg_id: T) -> Self {
         self.blacklist.push(arg_id.into());
         self
@@ -1052,6 +1661,38 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// Sets a conflict between this argument and a subcommand by name. Using this argument
+    /// together with an invocation of the named subcommand produces an
+    /// [`ErrorKind::ArgumentConflict`]; other subcommands are unaffected.
+    ///
+    /// **NOTE:** Unlike [`Arg::conflicts_with`], this conflict is one-directional and must be
+    /// declared on the argument; subcommands carry no conflict rules of their own.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("dry-run")
+    ///         .long("dry-run")
+    ///         .global(true)
+    ///         .conflicts_with_subcommand("commit"))
+    ///     .subcommand(App::new("commit"))
+    ///     .subcommand(App::new("status"))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--dry-run", "commit"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::ArgumentConflict);
+    /// ```
+    /// [`Arg::conflicts_with`]: ./struct.Arg.html#method.conflicts_with
+    /// [`ErrorKind::ArgumentConflict`]: ./enum.ErrorKind.html#variant.ArgumentConflict
+    pub fn conflicts_with_subcommand(mut self, name: &'help str) -> Self {
+        self.blacklisted_subcommands.push(name);
+        self
+    }
+
     /// Set an exclusive argument by name. An exclusive argument conflict with every other flag
     /// and must be always passed alone.
     ///
@@ -1236,6 +1877,69 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// Specifies that this argument overrides *every* other argument of the `App`, without
+    /// having to list each sibling as [`Arg::overrides_with_all`] requires. This is useful for
+    /// "reset to this mode" style flags that should erase whatever was specified before them.
+    ///
+    /// The override list is resolved when the `App` is built, at which point every sibling id
+    /// except this argument's own is added to its overrides (the self-override rules documented
+    /// for [`Arg::overrides_with`] apply unchanged). This mirrors how [`Arg::exclusive`] is
+    /// evaluated against all args.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::from("--mode-a 'first mode'"))
+    ///     .arg(Arg::from("--mode-b 'second mode'"))
+    ///     .arg(Arg::from("--mode-c 'third mode'")
+    ///         .overrides_with_everything(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--mode-a", "--mode-b", "--mode-c"]);
+    ///
+    /// assert!(m.is_present("mode-c"));
+    /// assert!(!m.is_present("mode-a"));
+    /// assert!(!m.is_present("mode-b"));
+    /// ```
+    /// [`Arg::overrides_with`]: ./struct.Arg.html#method.overrides_with
+    /// [`Arg::overrides_with_all`]: ./struct.Arg.html#method.overrides_with_all
+    /// [`Arg::exclusive`]: ./struct.Arg.html#method.exclusive
+    #[inline]
+    pub fn overrides_with_everything(mut self, o: bool) -> Self {
+        self.overrides_everything = o;
+        self
+    }
+
+    /// Self-overrides are normally ignored for args with one of the `Multiple*` settings, so
+    /// repeated occurrences keep appending values. Setting this makes a multiple-value option
+    /// that lists itself via [`Arg::overrides_with`] actually reset on each occurrence, i.e.
+    /// "last occurrence wins, discarding earlier values".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("set")
+    ///         .long("set")
+    ///         .takes_value(true)
+    ///         .multiple(true)
+    ///         .overrides_with("set")
+    ///         .allow_self_override(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--set", "a", "b", "--set", "c"
+    ///     ]);
+    ///
+    /// assert_eq!(m.values_of("set").unwrap().collect::<Vec<_>>(), ["c"]);
+    /// ```
+    /// [`Arg::overrides_with`]: ./struct.Arg.html#method.overrides_with
+    #[inline]
+    pub fn allow_self_override(mut self, allow: bool) -> Self {
+        self.allow_self_override = allow;
+        self
+    }
+
     /// Sets an argument by name that is required when this one is present I.e. when
     /// using this argument, the following argument *must* be present.
     ///
@@ -1288,11 +1992,49 @@ impl<'help> Arg<'help> {
     /// assert!(res.is_err());
     /// assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
     /// ```
+    /// A target argument that carries a [`Arg::default_value`] counts as present, so its
+    /// default satisfies the requirement; use [`Arg::requires_explicit`] to demand the target
+    /// be supplied by the user.
+    ///
     /// [`Arg::requires(name)`]: ./struct.Arg.html#method.requires
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`Arg::requires_explicit`]: ./struct.Arg.html#method.requires_explicit
     /// [Conflicting]: ./struct.Arg.html#method.conflicts_with
     /// [override]: ./struct.Arg.html#method.overrides_with
     pub fn requires<T: Key>(mut self, arg_id: T) -> Self {
-        self.requires.push((None, arg_id.into()));
+        self.requires
+            .push((RequiresPredicate::Always, arg_id.into()));
+        self
+    }
+
+    /// Demands that arguments named in this arg's [`Arg::requires`] rules were *explicitly*
+    /// supplied: a target present only through its [`Arg::default_value`] no longer satisfies
+    /// the requirement.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("cfg")
+    ///         .long("config")
+    ///         .requires("input")
+    ///         .requires_explicit(true))
+    ///     .arg(Arg::new("input")
+    ///         .long("input")
+    ///         .default_value("-"))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--config"
+    ///     ]);
+    ///
+    /// assert!(res.is_err()); // `input` was only defaulted, not supplied
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+    /// ```
+    /// [`Arg::requires`]: ./struct.Arg.html#method.requires
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    #[inline]
+    pub fn requires_explicit(mut self, r: bool) -> Self {
+        self.requires_explicit = r;
         self
     }
 
@@ -1359,7 +2101,89 @@ impl<'help> Arg<'help> {
     /// [Conflicting]: ./struct.Arg.html#method.conflicts_with
     /// [override]: ./struct.Arg.html#method.overrides_with
     pub fn requires_if<T: Key>(mut self, val: &'help str, arg_id: T) -> Self {
-        self.requires.push((Some(val), arg_id.into()));
+        self.requires
+            .push((RequiresPredicate::Equals(OsStr::new(val)), arg_id.into()));
+        self
+    }
+
+    /// Require another argument if this arg was present on runtime, and its value equals to
+    /// `val`, where `val` may contain non-UTF8 bytes. Apart from the [`OsStr`] comparison
+    /// value this works exactly like [`Arg::requires_if`]; no lossy conversion takes place
+    /// when values are compared at parse time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// # use std::ffi::OsStr;
+    /// Arg::new("config")
+    ///     .requires_if_os(OsStr::new("val"), "arg")
+    /// # ;
+    /// ```
+    /// [`OsStr`]: https://doc.rust-lang.org/std/ffi/struct.OsStr.html
+    /// [`Arg::requires_if`]: ./struct.Arg.html#method.requires_if
+    pub fn requires_if_os<T: Key>(mut self, val: &'help OsStr, arg_id: T) -> Self {
+        self.requires
+            .push((RequiresPredicate::Equals(val), arg_id.into()));
+        self
+    }
+
+    /// Require another argument if this arg was present on runtime, and its value does *not*
+    /// equal `val`. This is the inverse of [`Arg::requires_if`] and is useful when one value
+    /// acts as a self-contained default while every other value needs supporting configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::new("mode")
+    ///     .requires_if_not("default", "config")
+    /// # ;
+    /// ```
+    ///
+    /// Setting `Arg::requires_if_not(val, arg)` requires that the `arg` be used at runtime
+    /// unless the defining argument's value is equal to `val`.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("mode")
+    ///         .takes_value(true)
+    ///         .requires_if_not("default", "config")
+    ///         .long("mode"))
+    ///     .arg(Arg::new("config")
+    ///         .takes_value(true)
+    ///         .long("config"))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--mode", "custom"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+    /// ```
+    ///
+    /// Supplying the exempt value does not require the other argument.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("mode")
+    ///         .takes_value(true)
+    ///         .requires_if_not("default", "config")
+    ///         .long("mode"))
+    ///     .arg(Arg::new("config")
+    ///         .takes_value(true)
+    ///         .long("config"))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--mode", "default"
+    ///     ]);
+    ///
+    /// assert!(res.is_ok());
+    /// ```
+    /// [`Arg::requires_if`]: ./struct.Arg.html#method.requires_if
+    pub fn requires_if_not<T: Key>(mut self, val: &'help str, arg_id: T) -> Self {
+        self.requires
+            .push((RequiresPredicate::NotEquals(OsStr::new(val)), arg_id.into()));
         self
     }
 
@@ -1416,7 +2240,51 @@ impl<'help> Arg<'help> {
     /// [override]: ./struct.Arg.html#method.overrides_with
     pub fn requires_ifs<T: Key>(mut self, ifs: &[(&'help str, T)]) -> Self {
         self.requires
-            .extend(ifs.iter().map(|(val, arg)| (Some(*val), Id::from(arg))));
+            .extend(ifs.iter().map(|(val, arg)| {
+                (RequiresPredicate::Equals(OsStr::new(*val)), Id::from(arg))
+            }));
+        self
+    }
+
+    /// Requires *all* of the specified arguments if this arg's value equals `val`. This is the
+    /// grouped form of chaining [`Arg::requires_if`] with the same `val` for each target: when the
+    /// value matches, every listed argument becomes required; otherwise none of them are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::new("config")
+    ///     .requires_all_if("full", &["output", "format"])
+    /// # ;
+    /// ```
+    ///
+    /// Setting the value to `val` while supplying only some of the listed arguments is an error.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("cfg")
+    ///         .takes_value(true)
+    ///         .requires_all_if("full", &["output", "format"])
+    ///         .long("config"))
+    ///     .arg(Arg::new("output")
+    ///         .long("output"))
+    ///     .arg(Arg::new("format")
+    ///         .long("format"))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--config", "full", "--output"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+    /// ```
+    /// [`Arg::requires_if`]: ./struct.Arg.html#method.requires_if
+    pub fn requires_all_if<T: Key>(mut self, val: &'help str, arg_ids: &[T]) -> Self {
+        self.requires
+            .extend(arg_ids.iter().map(|arg| {
+                (RequiresPredicate::Equals(OsStr::new(val)), Id::from(arg))
+            }));
         self
     }
 
@@ -1649,6 +2517,36 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// Allows specifying that this argument is [required] whenever any member of the named
+    /// [`ArgGroup`] is present at runtime. Group membership is resolved at parse time, so args
+    /// added to the group later still count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("format")
+    ///         .required_if_group_present("output")
+    ///         .takes_value(true)
+    ///         .long("format"))
+    ///     .arg(Arg::new("json").long("json"))
+    ///     .arg(Arg::new("yaml").long("yaml"))
+    ///     .group(ArgGroup::new("output").args(&["json", "yaml"]))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--json"
+    ///     ]);
+    ///
+    /// assert!(res.is_err()); // a group member was used without --format
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+    /// ```
+    /// [required]: ./struct.Arg.html#method.required
+    /// [`ArgGroup`]: ./struct.ArgGroup.html
+    pub fn required_if_group_present<T: Key>(mut self, group_id: T) -> Self {
+        self.r_if_groups.push(group_id.into());
+        self
+    }
+
     /// Sets multiple arguments by names that are required when this one is present I.e. when
     /// using this argument, the following arguments *must* be present.
     ///
@@ -1711,7 +2609,8 @@ impl<'help> Arg<'help> {
     /// [Conflicting]: ./struct.Arg.html#method.conflicts_with
     /// [override]: ./struct.Arg.html#method.overrides_with
     pub fn requires_all<T: Key>(mut self, names: &[T]) -> Self {
-        self.requires.extend(names.iter().map(|s| (None, s.into())));
+        self.requires
+            .extend(names.iter().map(|s| (RequiresPredicate::Always, s.into())));
         self
     }
 
@@ -1732,6 +2631,8 @@ impl<'help> Arg<'help> {
     ///
     /// # Panics
     ///
+    /// This method [`panic!`]s directly when given `0`, since positional indices start at `1`.
+    ///
     /// Although not in this method directly, [`App`] will [`panic!`] if indexes are skipped (such
     /// as defining `index(1)` and `index(3)` but not `index(2)`, or a positional argument is
     /// defined as multiple and is not the highest index
@@ -1767,10 +2668,27 @@ impl<'help> Arg<'help> {
     /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
     #[inline]
     pub fn index(mut self, idx: usize) -> Self {
+        assert!(idx > 0, "positional indices start at 1");
         self.index = Some(idx);
         self
     }
 
+    /// Reports whether an explicit [index] has been set for this argument, i.e. whether it was
+    /// declared as a positional argument at a fixed position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// assert!(Arg::new("config").index(1).is_indexed());
+    /// assert!(!Arg::new("config").long("config").is_indexed());
+    /// ```
+    /// [index]: ./struct.Arg.html#method.index
+    #[inline]
+    pub fn is_indexed(&self) -> bool {
+        self.index.is_some()
+    }
+
     /// Specifies a value that *stops* parsing multiple values of a give argument. By default when
     /// one sets [`multiple(true)`] on an argument, clap will continue parsing values for that
     /// argument until it reaches another valid argument, or one of the other more specific settings
@@ -1823,6 +2741,35 @@ impl<'help> Arg<'help> {
         self.takes_value(true)
     }
 
+    /// Works like [`Arg::value_terminator`], except that the terminator itself is kept as the
+    /// final value instead of being discarded, for cases where the collected values are
+    /// re-emitted downstream verbatim.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("cmds")
+    ///         .takes_value(true)
+    ///         .multiple(true)
+    ///         .value_terminator_keep(";"))
+    ///     .arg(Arg::new("location"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "a", "b", ";", "/home/clap"
+    ///     ]);
+    /// let cmds: Vec<_> = m.values_of("cmds").unwrap().collect();
+    /// assert_eq!(&cmds, &["a", "b", ";"]);
+    /// assert_eq!(m.value_of("location"), Some("/home/clap"));
+    /// ```
+    /// [`Arg::value_terminator`]: ./struct.Arg.html#method.value_terminator
+    #[inline]
+    pub fn value_terminator_keep(mut self, term: &'help str) -> Self {
+        self.terminator = Some(term);
+        self.keep_terminator = true;
+        self.takes_value(true)
+    }
+
     /// Specifies a list of possible values for this argument. At runtime, `clap` verifies that
     /// only one of the specified values was used, or fails with an error message.
     ///
@@ -1875,16 +2822,60 @@ impl<'help> Arg<'help> {
         self.takes_value(true)
     }
 
-    /// Specifies a possible value for this argument, one at a time. At runtime, `clap` verifies
-    /// that only one of the specified values was used, or fails with error message.
+    /// Installs the variants of an [`ArgEnum`] implementor as the possible values for this
+    /// argument, keeping the valid set in sync with the Rust enum instead of duplicating
+    /// strings. Matching is case insensitive, mirroring `ArgEnum::from_str`.
     ///
-    /// **NOTE:** This setting only applies to [options] and [positional arguments]
+    /// **NOTE:** implicitly sets [`Arg::takes_value(true)`] and [`ArgSettings::IgnoreCase`]
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use clap::{App, Arg};
-    /// Arg::new("mode")
+    /// # use clap::{App, Arg, ArgEnum};
+    /// enum Mode {
+    ///     Fast,
+    ///     Slow,
+    /// }
+    ///
+    /// impl ArgEnum for Mode {
+    ///     const VARIANTS: &'static [&'static str] = &["fast", "slow"];
+    ///
+    ///     fn from_str(input: &str, _case_insensitive: bool) -> Result<Self, String> {
+    ///         match input {
+    ///             i if i.eq_ignore_ascii_case("fast") => Ok(Mode::Fast),
+    ///             i if i.eq_ignore_ascii_case("slow") => Ok(Mode::Slow),
+    ///             _ => Err(format!("Invalid variant: {}", input)),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("mode")
+    ///         .long("mode")
+    ///         .possible_values_from::<Mode>())
+    ///     .get_matches_from(vec![
+    ///         "prog", "--mode", "FAST"
+    ///     ]);
+    /// assert_eq!(m.value_of("mode"), Some("FAST"));
+    /// ```
+    /// [`ArgEnum`]: ./trait.ArgEnum.html
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    /// [`ArgSettings::IgnoreCase`]: ./enum.ArgSettings.html#variant.IgnoreCase
+    #[cfg(feature = "derive")]
+    pub fn possible_values_from<E: crate::ArgEnum>(self) -> Self {
+        self.possible_values(E::VARIANTS).case_insensitive(true)
+    }
+
+    /// Specifies a possible value for this argument, one at a time. At runtime, `clap` verifies
+    /// that only one of the specified values was used, or fails with error message.
+    ///
+    /// **NOTE:** This setting only applies to [options] and [positional arguments]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// Arg::new("mode")
     ///     .takes_value(true)
     ///     .possible_value("fast")
     ///     .possible_value("slow")
@@ -1933,6 +2924,83 @@ impl<'help> Arg<'help> {
         self.takes_value(true)
     }
 
+    /// Adds a possible value together with a help string describing it. The value validates
+    /// exactly like one added via [`Arg::possible_value`]; the pair is additionally recorded so
+    /// external help renderers can reproduce rich value listings through
+    /// [`Arg::get_possible_values_help`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("mode")
+    ///         .long("mode")
+    ///         .possible_value_with_help("fast", "finishes quickly")
+    ///         .possible_value_with_help("slow", "takes its time"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--mode", "fast"
+    ///     ]);
+    /// assert!(m.is_present("mode"));
+    /// ```
+    /// [`Arg::possible_value`]: ./struct.Arg.html#method.possible_value
+    /// [`Arg::get_possible_values_help`]: ./struct.Arg.html#method.get_possible_values_help
+    pub fn possible_value_with_help(mut self, name: &'help str, help: &'help str) -> Self {
+        self.possible_vals.push(name);
+        self.possible_vals_help.push((name, help));
+        self.takes_value(true)
+    }
+
+    /// Adds a possible value together with aliases that validate as if the canonical value had
+    /// been given. Only `canonical` is listed as a choice in the help message; use
+    /// [`Arg::show_possible_value_aliases`] to render the aliases next to it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("mode")
+    ///         .long("mode")
+    ///         .possible_value_aliased("fast", &["f", "quick"])
+    ///         .possible_value("slow"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--mode", "quick"
+    ///     ]);
+    /// assert!(m.is_present("mode"));
+    /// ```
+    /// [`Arg::show_possible_value_aliases`]: ./struct.Arg.html#method.show_possible_value_aliases
+    pub fn possible_value_aliased(
+        mut self,
+        canonical: &'help str,
+        aliases: &[&'help str],
+    ) -> Self {
+        self.possible_vals.push(canonical);
+        self.possible_val_aliases
+            .extend(aliases.iter().map(|&als| (als, canonical)));
+        self.takes_value(true)
+    }
+
+    /// When set, the help message renders each possible value together with its registered
+    /// aliases, e.g. `fast (aliases: f, quick)`. Possible values without aliases render
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::new("mode")
+    ///     .long("mode")
+    ///     .possible_value_aliased("fast", &["f", "quick"])
+    ///     .show_possible_value_aliases(true)
+    /// # ;
+    /// ```
+    #[inline]
+    pub fn show_possible_value_aliases(mut self, b: bool) -> Self {
+        self.show_pv_aliases = b;
+        self
+    }
+
     /// Specifies the name of the [`ArgGroup`] the argument belongs to.
     ///
     /// # Examples
@@ -2013,6 +3081,10 @@ impl<'help> Arg<'help> {
     /// [`Arg::multiple(true)`] would allow `-f <file> <file> <file> -f <file> <file> <file>` where
     /// as *not* setting [`Arg::multiple(true)`] would only allow one occurrence of this argument.
     ///
+    /// **NOTE:** `number_of_values(0)` means "takes no value": it unsets
+    /// [`Arg::takes_value(true)`] and the argument behaves as a plain flag, keeping data-driven
+    /// builders safe.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -2040,8 +3112,17 @@ impl<'help> Arg<'help> {
     /// assert_eq!(res.unwrap_err().kind, ErrorKind::WrongNumberOfValues);
     /// ```
     /// [`Arg::multiple(true)`]: ./struct.Arg.html#method.multiple
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
     #[inline]
     pub fn number_of_values(mut self, qty: usize) -> Self {
+        if qty == 0 {
+            // Data-driven builders use `0` to mean "plain flag"; a value-taking arg that
+            // demands zero values could never be satisfied
+            self.num_vals = None;
+            return self
+                .unset_setting(ArgSettings::TakesValue)
+                .unset_setting(ArgSettings::MultipleValues);
+        }
         self.num_vals = Some(qty);
         self.takes_value(true).multiple_values(true)
     }
@@ -2060,6 +3141,10 @@ impl<'help> Arg<'help> {
     /// to be passed to the closure. This performance hit is extremely minimal in the grand
     /// scheme of things.
     ///
+    /// **NOTE:** When combined with [`Arg::validator_os`], the `OsStr` validator runs first; this
+    /// validator only runs if it passed. A value that is not valid UTF-8 fails this validator
+    /// with a "value is not valid UTF-8" error instead of being silently skipped.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -2082,6 +3167,7 @@ impl<'help> Arg<'help> {
     /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
     /// [`Err(String)`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
     /// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+    /// [`Arg::validator_os`]: ./struct.Arg.html#method.validator_os
     pub fn validator<F, O, E>(mut self, mut f: F) -> Self
     where
         F: FnMut(&str) -> Result<O, E> + Send + 'help,
@@ -2093,9 +3179,79 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// Controls the ordering of the [`Arg::validator`] relative to the
+    /// [`Arg::possible_values`] check when both are set. By default the possible-values
+    /// lookup runs first, so a value outside the list fails with
+    /// [`ErrorKind::InvalidValue`] before the validator is consulted. Setting this runs the
+    /// validator first, letting a cheap check reject early (useful with very large
+    /// possible-value lists); a value failing both then reports
+    /// [`ErrorKind::ValueValidation`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("digit")
+    ///         .long("digit")
+    ///         .possible_values(&["1", "2", "3"])
+    ///         .validator(|v| v.parse::<u32>().map_err(|e| e.to_string()))
+    ///         .validate_before_possible_values(true))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--digit", "x"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::ValueValidation);
+    /// ```
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    /// [`Arg::possible_values`]: ./struct.Arg.html#method.possible_values
+    /// [`ErrorKind::InvalidValue`]: ./enum.ErrorKind.html#variant.InvalidValue
+    /// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+    #[inline]
+    pub fn validate_before_possible_values(mut self, b: bool) -> Self {
+        self.validate_before_possible_vals = b;
+        self
+    }
+
+    /// Runs the [`Arg::validator`] only against the final retained value instead of every
+    /// value, for expensive validators on multi-occurrence options where only the last value
+    /// matters. With [`ArgSettings::MultipleValues`] this likewise validates only the last
+    /// value of the collection; all other checks (possible values, [`Arg::validator_os`],
+    /// empty-value rules) still apply to every value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("level")
+    ///         .long("level")
+    ///         .takes_value(true)
+    ///         .multiple_occurrences(true)
+    ///         .validator(|v| v.parse::<u32>().map_err(|e| e.to_string()))
+    ///         .validate_last_only(true))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--level", "not-a-number", "--level", "3"
+    ///     ]);
+    ///
+    /// assert!(res.is_ok()); // only the final "3" was validated
+    /// ```
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    /// [`Arg::validator_os`]: ./struct.Arg.html#method.validator_os
+    /// [`ArgSettings::MultipleValues`]: ./enum.ArgSettings.html#variant.MultipleValues
+    #[inline]
+    pub fn validate_last_only(mut self, b: bool) -> Self {
+        self.validate_last_only = b;
+        self
+    }
+
     /// Works identically to Validator but is intended to be used with values that could
     /// contain non UTF-8 formatted strings.
     ///
+    /// **NOTE:** When combined with [`Arg::validator`], this validator runs first on the raw
+    /// `OsStr`; the str validator only runs if this one passed and the value is valid UTF-8.
+    ///
     /// # Examples
     ///
     #[cfg_attr(not(unix), doc = " ```ignore")]
@@ -2123,6 +3279,7 @@ impl<'help> Arg<'help> {
     /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
     /// [`Err(String)`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
     /// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
     pub fn validator_os<F, O, E>(mut self, mut f: F) -> Self
     where
         F: FnMut(&OsStr) -> Result<O, E> + Send + 'help,
@@ -2134,6 +3291,328 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// Allows one to perform a custom validation over *all* values of the argument at once. You
+    /// provide a closure which accepts the full slice of values and returns a [`Result`] where
+    /// the [`Err(String)`] is a message displayed to the user.
+    ///
+    /// This is distinct from [`Arg::validator`], which checks each value in isolation; the
+    /// closure given here runs once after every value has passed element-wise validation, making
+    /// it suitable for constraints over the collection such as "values must be unique".
+    ///
+    /// **NOTE:** All notes for [`Arg::validator`] regarding the error message and performance
+    /// also hold for `validator_all`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// fn unique(vals: &[&str]) -> Result<(), String> {
+    ///     for (i, v) in vals.iter().enumerate() {
+    ///         if vals[..i].contains(v) {
+    ///             return Err(format!("The value '{}' was given more than once", v));
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("tags")
+    ///         .long("tags")
+    ///         .use_delimiter(true)
+    ///         .multiple(true)
+    ///         .validator_all(unique))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--tags", "a,b"
+    ///     ]);
+    /// assert!(res.is_ok());
+    /// ```
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
+    /// [`Err(String)`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    pub fn validator_all<F, O, E>(mut self, mut f: F) -> Self
+    where
+        F: FnMut(&[&str]) -> Result<O, E> + Send + 'help,
+        E: Into<Box<dyn Error + Send + Sync + 'static>>,
+    {
+        self.validator_all = Some(Arc::new(Mutex::new(move |vals: &[&str]| {
+            f(vals).map(|_| ()).map_err(|e| e.into())
+        })));
+        self
+    }
+
+    /// Allows a custom validator that carries suggestions for the user along with the failure
+    /// message. The `Err` side is a `(message, candidates)` pair; the produced error renders
+    /// the closest candidate as a "Did you mean '<candidate>'?" hint, like clap does for
+    /// unknown flags. An empty candidate list renders the message alone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("format")
+    ///         .long("format")
+    ///         .takes_value(true)
+    ///         .validator_with_suggestions(|s| {
+    ///             let known = ["json", "yaml", "toml"];
+    ///             if known.contains(&s) {
+    ///                 Ok(())
+    ///             } else {
+    ///                 Err((
+    ///                     format!("unknown format '{}'", s),
+    ///                     known.iter().map(|k| k.to_string()).collect(),
+    ///                 ))
+    ///             }
+    ///         }))
+    ///     .try_get_matches_from(vec!["prog", "--format", "jsn"]);
+    ///
+    /// assert!(res.is_err());
+    /// assert!(res.unwrap_err().to_string().contains("Did you mean 'json'?"));
+    /// ```
+    pub fn validator_with_suggestions<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&str) -> Result<(), (String, Vec<String>)> + Send + 'help,
+    {
+        self.validator_suggestions = Some(Arc::new(Mutex::new(f)));
+        self
+    }
+
+    /// Allows a custom validator which also receives the name of the argument being validated,
+    /// as `(arg_name, value)`. This makes a single validation function reusable across several
+    /// args while still producing messages that cite which one failed.
+    ///
+    /// This stores into its own slot and runs in addition to [`Arg::validator`], after it.
+    ///
+    /// **NOTE:** All notes for [`Arg::validator`] regarding the error message and performance
+    /// also hold for `validator_named`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// fn exists(name: &str, v: &str) -> Result<(), String> {
+    ///     if v.starts_with('/') { return Ok(()); }
+    ///     Err(format!("path '{}' for --{} must be absolute", v, name))
+    /// }
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("config")
+    ///         .long("config")
+    ///         .takes_value(true)
+    ///         .validator_named(exists))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--config", "rel/path"
+    ///     ]);
+    /// assert!(res.is_err());
+    /// assert!(res.unwrap_err().to_string().contains("for --config"));
+    /// ```
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    pub fn validator_named<F, O, E>(mut self, mut f: F) -> Self
+    where
+        F: FnMut(&str, &str) -> Result<O, E> + Send + 'help,
+        E: Into<Box<dyn Error + Send + Sync + 'static>>,
+    {
+        self.validator_named = Some(Arc::new(Mutex::new(move |name: &str, val: &str| {
+            f(name, val).map(|_| ()).map_err(|e| e.into())
+        })));
+        self
+    }
+
+    /// Installs a non-failing validator: the closure inspects each value and may return a
+    /// warning message, which is printed to `stderr` during parsing while the parse itself
+    /// still succeeds. Useful for values that are suspicious but not invalid, complementing
+    /// the failing [`Arg::validator`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("jobs")
+    ///         .long("jobs")
+    ///         .takes_value(true)
+    ///         .validator_warn(|v| {
+    ///             if v.parse::<u32>().map_or(false, |n| n > 512) {
+    ///                 Some(format!("'{}' jobs is suspiciously high", v))
+    ///             } else {
+    ///                 None
+    ///             }
+    ///         }))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--jobs", "1024"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("jobs"), Some("1024"));
+    /// ```
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    pub fn validator_warn<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&str) -> Option<String> + Send + 'help,
+    {
+        self.validator_warn = Some(Arc::new(Mutex::new(f)));
+        self
+    }
+
+    /// Installs a deferred cross-field validator. Unlike [`Arg::validator`], which runs while
+    /// values are being checked in isolation, this closure runs in a second pass *after*
+    /// initial parsing, with read access to the partial [`ArgMatches`], so it can compare this
+    /// argument's values against other parsed args. Failures surface as
+    /// [`ErrorKind::ValueValidation`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("min").long("min").takes_value(true))
+    ///     .arg(Arg::new("max")
+    ///         .long("max")
+    ///         .takes_value(true)
+    ///         .validator_with_matches(|v, m| {
+    ///             let min: u32 = m.value_of("min").unwrap_or("0").parse().unwrap_or(0);
+    ///             if v.parse::<u32>().map_or(false, |max| max >= min) {
+    ///                 Ok(())
+    ///             } else {
+    ///                 Err(String::from("--max must be >= --min"))
+    ///             }
+    ///         }))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--min", "10", "--max", "5"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::ValueValidation);
+    /// ```
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    /// [`ArgMatches`]: ./struct.ArgMatches.html
+    /// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+    pub fn validator_with_matches<F, O, E>(mut self, mut f: F) -> Self
+    where
+        F: FnMut(&str, &crate::ArgMatches) -> Result<O, E> + Send + 'help,
+        E: Into<Box<dyn Error + Send + Sync + 'static>>,
+    {
+        self.validator_matches = Some(Arc::new(Mutex::new(
+            move |val: &str, matches: &crate::ArgMatches| {
+                f(val, matches).map(|_| ()).map_err(|e| e.into())
+            },
+        )));
+        self
+    }
+
+    /// Specifies the process exit code [`Error::exit`] uses when validation of *this* argument's
+    /// values fails, instead of the default `1`. This covers failures from [`Arg::validator`],
+    /// [`Arg::validator_os`] and [`Arg::possible_values`] checks, and lets scripts distinguish
+    /// them from other parse errors. The code is carried on the returned [`Error`] as
+    /// [`Error::exit_code`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("level")
+    ///         .long("level")
+    ///         .possible_values(&["low", "high"])
+    ///         .validation_exit_code(3))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--level", "bogus",
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().exit_code, Some(3));
+    /// ```
+    /// [`Error::exit`]: ./struct.Error.html#method.exit
+    /// [`Error::exit_code`]: ./struct.Error.html#structfield.exit_code
+    /// [`Error`]: ./struct.Error.html
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    /// [`Arg::validator_os`]: ./struct.Arg.html#method.validator_os
+    /// [`Arg::possible_values`]: ./struct.Arg.html#method.possible_values
+    #[inline]
+    pub fn validation_exit_code(mut self, code: i32) -> Self {
+        self.validation_exit_code = Some(code);
+        self
+    }
+
+    /// Allows one to rewrite the full list of values of the argument after parsing. You provide
+    /// a closure which receives every collected value and returns the list to store in its
+    /// place, which is what [`ArgMatches::values_of`] will later report. This is useful for
+    /// normalizations over the whole collection such as sorting, deduping, or expanding globs.
+    ///
+    /// The closure runs once per argument, after all per-value validators (and
+    /// [`Arg::validator_all`]) have accepted the input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("tags")
+    ///         .long("tags")
+    ///         .takes_value(true)
+    ///         .multiple_values(true)
+    ///         .map_values(|mut vals| {
+    ///             vals.sort();
+    ///             vals.dedup();
+    ///             vals
+    ///         }))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--tags", "b", "a", "b"
+    ///     ]);
+    /// let vals: Vec<_> = m.values_of("tags").unwrap().collect();
+    /// assert_eq!(vals, ["a", "b"]);
+    /// ```
+    /// [`ArgMatches::values_of`]: ./struct.ArgMatches.html#method.values_of
+    /// [`Arg::validator_all`]: ./struct.Arg.html#method.validator_all
+    pub fn map_values<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(Vec<String>) -> Vec<String> + Send + 'help,
+    {
+        self.value_mapper = Some(Arc::new(Mutex::new(f)));
+        self
+    }
+
+    /// Transforms each value before it is stored, so [`ArgMatches::value_of_os`] reports the
+    /// converted value instead of the raw input. Unlike [`Arg::validator_os`] this rewrites the
+    /// value; returning `Err` rejects it with [`ErrorKind::ValueValidation`] just like a
+    /// validator would. Typical use is canonicalizing paths once at parse time so downstream
+    /// code never has to.
+    ///
+    /// The closure runs once per value, after all per-value validators have accepted the input
+    /// and before [`Arg::map_values`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::ffi::OsString;
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("file")
+    ///         .long("file")
+    ///         .takes_value(true)
+    ///         .map_os(|v| {
+    ///             let mut owned = OsString::from("/base/");
+    ///             owned.push(v);
+    ///             Ok::<_, String>(owned)
+    ///         }))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--file", "a.txt"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of_os("file"), Some(OsString::from("/base/a.txt").as_os_str()));
+    /// ```
+    /// [`ArgMatches::value_of_os`]: ./struct.ArgMatches.html#method.value_of_os
+    /// [`Arg::validator_os`]: ./struct.Arg.html#method.validator_os
+    /// [`Arg::map_values`]: ./struct.Arg.html#method.map_values
+    /// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+    pub fn map_os<F, E>(mut self, mut f: F) -> Self
+    where
+        F: FnMut(&OsStr) -> Result<OsString, E> + Send + 'help,
+        E: Into<Box<dyn Error + Send + Sync + 'static>>,
+    {
+        self.value_mapper_os = Some(Arc::new(Mutex::new(move |v: &OsStr| {
+            f(v).map_err(|e| e.into())
+        })));
+        self
+    }
+
     /// Validates the argument via the given regular expression.
     ///
     /// As regular expressions are not very user friendly, the additional `err_message` should
@@ -2154,55 +3633,151 @@ impl<'help> Arg<'help> {
     /// provided regular expression.
     ///
     /// # Examples
-    /// You can use the classical `"\d+"` regular expression to match digits only:
+    /// You can use the classical `"\d+"` regular expression to match digits only:
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// use regex::Regex;
+    ///
+    /// let digits = Regex::new(r"\d+").unwrap();
+    ///
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("digits")
+    ///         .index(1)
+    ///         .validator_regex(&digits, "only digits are allowed"))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "12345"
+    ///     ]);
+    /// assert!(res.is_ok());
+    /// assert_eq!(res.unwrap().value_of("digits"), Some("12345"));
+    /// ```
+    /// However, any valid `Regex` can be used:
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// use regex::Regex;
+    ///
+    /// let priority = Regex::new(r"[A-C]").unwrap();
+    ///
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("priority")
+    ///         .index(1)
+    ///         .validator_regex(priority, "only priorities A, B or C are allowed"))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "12345"
+    ///     ]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.err().unwrap().kind, ErrorKind::ValueValidation)
+    /// ```
+    /// [`Arg::validator()`]: ./struct.Arg.html#method.validator
+    /// [`RegexRef`]: ./struct.RegexRef.html
+    #[cfg(feature = "regex")]
+    pub fn validator_regex(
+        self,
+        regex: impl Into<RegexRef<'help>>,
+        err_message: &'help str,
+    ) -> Self {
+        let regex = regex.into();
+        self.validator(move |s: &str| {
+            if regex.is_match(s) {
+                Ok(())
+            } else {
+                Err(err_message)
+            }
+        })
+    }
+
+    /// Requires that the values of this argument, parsed as numbers, form a strictly increasing
+    /// sequence. This is useful for args describing breakpoints, intervals, or thresholds where
+    /// ordering matters and per-value validators cannot see neighbouring values.
+    ///
+    /// The check runs after all values have been collected. A value that does not parse as a
+    /// number is an error, as is the first pair of values that fails to strictly increase (equal
+    /// values are rejected too); the offending pair is named in the error message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("breakpoints")
+    ///         .long("breakpoints")
+    ///         .takes_value(true)
+    ///         .multiple_values(true)
+    ///         .require_increasing_values(true))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--breakpoints", "1", "3", "2"
+    ///     ]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::ValueValidation);
+    /// ```
+    #[inline]
+    pub fn require_increasing_values(self, r: bool) -> Self {
+        if r {
+            self.setting(ArgSettings::RequireIncreasingValues)
+        } else {
+            self.unset_setting(ArgSettings::RequireIncreasingValues)
+        }
+    }
+
+    /// Validates the argument as a JSON document.
+    ///
+    /// The value must parse as JSON; on failure the parser's own message, including the position
+    /// of the offending token, is displayed to the user. All notes for [`Arg::validator()`]
+    /// regarding the error message and performance also hold for `validate_json`.
+    ///
+    /// # Examples
+    ///
     /// ```rust
     /// # use clap::{App, Arg};
-    /// use regex::Regex;
-    ///
-    /// let digits = Regex::new(r"\d+").unwrap();
-    ///
     /// let res = App::new("prog")
-    ///     .arg(Arg::new("digits")
-    ///         .index(1)
-    ///         .validator_regex(&digits, "only digits are allowed"))
+    ///     .arg(Arg::new("filter")
+    ///         .long("filter")
+    ///         .takes_value(true)
+    ///         .validate_json())
     ///     .try_get_matches_from(vec![
-    ///         "prog", "12345"
+    ///         "prog", "--filter", "{\"k\":1}"
     ///     ]);
     /// assert!(res.is_ok());
-    /// assert_eq!(res.unwrap().value_of("digits"), Some("12345"));
     /// ```
-    /// However, any valid `Regex` can be used:
-    /// ```rust
-    /// # use clap::{App, Arg, ErrorKind};
-    /// use regex::Regex;
+    /// [`Arg::validator()`]: ./struct.Arg.html#method.validator
+    #[cfg(feature = "json")]
+    pub fn validate_json(self) -> Self {
+        self.validator(|s: &str| {
+            serde_json::from_str::<serde_json::Value>(s)
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        })
+    }
+
+    /// Validates the argument as a JSON document conforming to the given JSON schema fragment.
     ///
-    /// let priority = Regex::new(r"[A-C]").unwrap();
+    /// The supported schema keywords are `type`, `enum`, `required`, `properties`, and `items`.
+    /// Validation errors cite the path of the failing field. All notes for [`Arg::validator()`]
+    /// regarding the error message and performance also hold for `validate_json_schema`.
+    ///
+    /// **NOTE:** This method panics if the schema itself is not valid JSON.
     ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
     /// let res = App::new("prog")
-    ///     .arg(Arg::new("priority")
-    ///         .index(1)
-    ///         .validator_regex(priority, "only priorities A, B or C are allowed"))
+    ///     .arg(Arg::new("filter")
+    ///         .long("filter")
+    ///         .takes_value(true)
+    ///         .validate_json_schema("{\"type\": \"object\", \"required\": [\"k\"]}"))
     ///     .try_get_matches_from(vec![
-    ///         "prog", "12345"
+    ///         "prog", "--filter", "{\"k\":1}"
     ///     ]);
-    /// assert!(res.is_err());
-    /// assert_eq!(res.err().unwrap().kind, ErrorKind::ValueValidation)
+    /// assert!(res.is_ok());
     /// ```
     /// [`Arg::validator()`]: ./struct.Arg.html#method.validator
-    /// [`RegexRef`]: ./struct.RegexRef.html
-    #[cfg(feature = "regex")]
-    pub fn validator_regex(
-        self,
-        regex: impl Into<RegexRef<'help>>,
-        err_message: &'help str,
-    ) -> Self {
-        let regex = regex.into();
+    #[cfg(feature = "json")]
+    pub fn validate_json_schema(self, schema: &'help str) -> Self {
+        let schema: serde_json::Value =
+            serde_json::from_str(schema).expect("JSON schema fragments must be valid JSON");
         self.validator(move |s: &str| {
-            if regex.is_match(s) {
-                Ok(())
-            } else {
-                Err(err_message)
-            }
+            let value: serde_json::Value = serde_json::from_str(s).map_err(|e| e.to_string())?;
+            json::validate_fragment(&schema, &value, "")
         })
     }
 
@@ -2331,8 +3906,47 @@ impl<'help> Arg<'help> {
         self.takes_value(true).multiple_values(true)
     }
 
+    /// Specifies that this option may appear with or without a value; in other words the value is
+    /// optional. This is a clearer spelling of `min_values(0)` for options taking a single value.
+    ///
+    /// When the option appears value-less and a [`Arg::default_missing_value`] was provided, that
+    /// default applies; otherwise no value is recorded. The option renders as `--opt[=<VAL>]` in
+    /// help and usage strings to signal that the value may be omitted.
+    ///
+    /// **NOTE:** implicitly sets [`Arg::takes_value(true)`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("color")
+    ///         .long("color")
+    ///         .value_optional(true)
+    ///         .default_missing_value("always"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--color"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("color"), Some("always"));
+    /// ```
+    /// [`Arg::default_missing_value`]: ./struct.Arg.html#method.default_missing_value
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    #[inline]
+    pub fn value_optional(self, optional: bool) -> Self {
+        if optional {
+            self.setting(ArgSettings::TakesValue)
+                .setting(ArgSettings::ValueOptional)
+        } else {
+            self.unset_setting(ArgSettings::ValueOptional)
+        }
+    }
+
     /// Specifies the separator to use when values are clumped together, defaults to `,` (comma).
     ///
+    /// The delimiter may be more than one character, in which case values are split on the full
+    /// substring (e.g. `a::b::c` with a delimiter of `"::"` yields three values).
+    ///
     /// **NOTE:** implicitly sets [`Arg::use_delimiter(true)`]
     ///
     /// **NOTE:** implicitly sets [`Arg::takes_value(true)`]
@@ -2355,15 +3969,42 @@ impl<'help> Arg<'help> {
     /// [`Arg::use_delimiter(true)`]: ./struct.Arg.html#method.use_delimiter
     /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
     #[inline]
-    pub fn value_delimiter(mut self, d: &str) -> Self {
-        self.val_delim = Some(
-            d.chars()
-                .next()
-                .expect("Failed to get value_delimiter from arg"),
-        );
+    pub fn value_delimiter(mut self, d: &'help str) -> Self {
+        assert!(!d.is_empty(), "Failed to get value_delimiter from arg");
+        self.val_delim = Some(d);
         self.takes_value(true).use_delimiter(true)
     }
 
+    /// Specifies that each value of this argument is a `KEY<delim>VALUE` pair, such as the
+    /// `--env KEY=VALUE` or `-D name=def` style of options. Every captured value must contain
+    /// the delimiter or parsing fails with [`ErrorKind::ValueValidation`]; use
+    /// [`ArgMatches::key_value_of`] to retrieve the split pair.
+    ///
+    /// **NOTE:** implicitly sets [`Arg::takes_value(true)`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("env")
+    ///         .long("env")
+    ///         .key_value_delimiter('='))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--env", "FOO=bar"
+    ///     ]);
+    ///
+    /// assert_eq!(m.key_value_of("env"), Some(("FOO", "bar")));
+    /// ```
+    /// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+    /// [`ArgMatches::key_value_of`]: ./struct.ArgMatches.html#method.key_value_of
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    #[inline]
+    pub fn key_value_delimiter(mut self, d: char) -> Self {
+        self.key_val_delim = Some(d);
+        self.takes_value(true)
+    }
+
     /// Specify multiple names for values of option arguments. These names are cosmetic only, used
     /// for help and usage strings only. The names are **not** used to access arguments. The values
     /// of the arguments are accessed in numeric order (i.e. if you specify two names `one` and
@@ -2433,6 +4074,38 @@ impl<'help> Arg<'help> {
         self.takes_value(true)
     }
 
+    /// Specify multiple display names for values *without* constraining how many values the
+    /// argument accepts. Unlike [`Arg::value_names`], which implicitly sets
+    /// [`Arg::number_of_values`] when more than one name is given, these names are labels only:
+    /// pair this with [`Arg::multiple_values(true)`] for help such as `--range <LOW> <HIGH>...`
+    /// while still accepting any number of values.
+    ///
+    /// **NOTE:** implicitly sets [`Arg::takes_value(true)`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("range")
+    ///         .long("range")
+    ///         .multiple_values(true)
+    ///         .value_labels(&["LOW", "HIGH"]))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--range", "1", "5", "9"
+    ///     ]);
+    ///
+    /// assert_eq!(m.values_of("range").unwrap().collect::<Vec<_>>(), ["1", "5", "9"]);
+    /// ```
+    /// [`Arg::value_names`]: ./struct.Arg.html#method.value_names
+    /// [`Arg::number_of_values`]: ./struct.Arg.html#method.number_of_values
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    /// [`Arg::multiple_values(true)`]: ./struct.Arg.html#method.multiple_values
+    pub fn value_labels(mut self, names: &[&'help str]) -> Self {
+        self.val_labels = true;
+        self.value_names(names)
+    }
+
     /// Specifies the name for value of [option] or [positional] arguments inside of help
     /// documentation. This name is cosmetic only, the name is **not** used to access arguments.
     /// This setting can be very helpful when describing the type of input the user should be
@@ -2486,6 +4159,140 @@ impl<'help> Arg<'help> {
         self.takes_value(true)
     }
 
+    /// Specifies the value name together with an inclusive numeric range that is both rendered
+    /// in help (as `<NAME:min-max>`) and enforced at parse time: values that are not integers or
+    /// fall outside the range are rejected with [`ErrorKind::ValueValidation`].
+    ///
+    /// **NOTE:** implicitly sets [`Arg::takes_value(true)`] and installs a [`Arg::validator`];
+    /// setting another validator afterwards replaces the range check.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let app = || {
+    ///     App::new("prog")
+    ///         .arg(Arg::new("port")
+    ///             .long("port")
+    ///             .value_name_ranged("PORT", 1, 65535))
+    /// };
+    ///
+    /// let res = app().try_get_matches_from(vec!["prog", "--port", "8080"]);
+    /// assert!(res.is_ok());
+    ///
+    /// let res = app().try_get_matches_from(vec!["prog", "--port", "70000"]);
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::ValueValidation);
+    /// ```
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    /// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+    pub fn value_name_ranged(mut self, name: &'help str, min: i64, max: i64) -> Self {
+        self.val_range = Some((min, max));
+        self.value_name(name).validator(move |v| {
+            v.parse::<i64>()
+                .map_err(|e| e.to_string())
+                .and_then(|n| {
+                    if n < min || n > max {
+                        Err(format!("{} is not in the range {}-{}", n, min, max))
+                    } else {
+                        Ok(())
+                    }
+                })
+        })
+    }
+
+    /// Specifies a unit hint rendered in help directly after the value name, e.g.
+    /// `--timeout <N> (seconds)`. This is pure help metadata, distinct from
+    /// [`Arg::value_name`]: it never affects parsing or usage strings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// let arg = Arg::new("timeout")
+    ///     .long("timeout")
+    ///     .value_name("N")
+    ///     .value_unit("seconds");
+    /// assert_eq!(arg.get_value_unit(), Some("seconds"));
+    /// ```
+    /// [`Arg::value_name`]: ./struct.Arg.html#method.value_name
+    #[inline]
+    pub fn value_unit(mut self, unit: &'help str) -> Self {
+        self.value_unit = Some(unit);
+        self
+    }
+
+    /// Specifies the name for the value at `index` (starting at `0`) inside of help
+    /// documentation, setting or overriding just that position. Unlike [`Arg::value_names`],
+    /// positions may be assigned sparsely and out of order; help renders them ordered by index.
+    ///
+    /// **NOTE:** implicitly sets [`Arg::takes_value(true)`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::new("io")
+    ///     .long("io")
+    ///     .value_name_at(1, "OUTPUT")
+    ///     .value_name_at(0, "INPUT")
+    /// # ;
+    /// ```
+    /// [`Arg::value_names`]: ./struct.Arg.html#method.value_names
+    /// [`Arg::takes_value(true)`]: ./enum.ArgSettings.html#variant.TakesValue
+    #[inline]
+    pub fn value_name_at(mut self, index: usize, name: &'help str) -> Self {
+        self.val_names.insert(index, name);
+        self.takes_value(true)
+    }
+
+    /// When enabled, value names render according to the conventional casing for the argument's
+    /// kind: uppercased for [options], lowercased for [positional arguments]. The transform is
+    /// applied at render time only; the stored literals (and hence lookups) are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::new("config")
+    ///     .long("config")
+    ///     .value_name("file")
+    ///     .value_name_case_by_kind(true) // renders as --config <FILE>
+    /// # ;
+    /// ```
+    /// [options]: ./struct.Arg.html#method.takes_value
+    /// [positional arguments]: ./struct.Arg.html#method.index
+    #[inline]
+    pub fn value_name_case_by_kind(mut self, b: bool) -> Self {
+        self.value_name_case_by_kind = b;
+        self
+    }
+
+    /// When enabled, any [`Arg::value_name`]/[`Arg::value_names`] not already uppercase is
+    /// uppercased for display, following the usual `<VALUE>` convention without having to
+    /// remember it at every call site. The transform uses [`str::to_uppercase`], so multibyte
+    /// and non-ASCII names are handled correctly; it applies at render time only and never
+    /// affects name-based access.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::new("config")
+    ///     .long("config")
+    ///     .value_name("file")
+    ///     .auto_uppercase_value_name(true) // renders as --config <FILE>
+    /// # ;
+    /// ```
+    /// [`Arg::value_name`]: ./struct.Arg.html#method.value_name
+    /// [`Arg::value_names`]: ./struct.Arg.html#method.value_names
+    /// [`str::to_uppercase`]: https://doc.rust-lang.org/std/primitive.str.html#method.to_uppercase
+    #[inline]
+    pub fn auto_uppercase_value_name(mut self, b: bool) -> Self {
+        self.auto_uppercase_value_name = b;
+        self
+    }
+
     /// Specifies the value of the argument when *not* specified at runtime.
     ///
     /// **NOTE:** If the user *does not* use this argument at runtime, [`ArgMatches::occurrences_of`]
@@ -2564,6 +4371,110 @@ impl<'help> Arg<'help> {
         self.default_values_os(&[val])
     }
 
+    /// Provides a default copied from another argument's resolved value: when this arg isn't
+    /// supplied, it takes on whatever value the referenced arg ended up with, including a
+    /// default the source itself was given. If the source is absent entirely, any
+    /// [`Arg::default_value`] on this arg applies as the fallback. Resolution happens during
+    /// match resolution, after the source arg is known.
+    ///
+    /// **NOTE:** implicitly sets [`Arg::takes_value(true)`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("project-dir")
+    ///         .long("project-dir")
+    ///         .takes_value(true))
+    ///     .arg(Arg::new("output-dir")
+    ///         .long("output-dir")
+    ///         .takes_value(true)
+    ///         .default_value_from_arg("project-dir"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--project-dir", "/work"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("output-dir"), Some("/work"));
+    /// ```
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    #[inline]
+    pub fn default_value_from_arg<T: Key>(mut self, arg_id: T) -> Self {
+        self.default_val_from = Some(arg_id.into());
+        self.takes_value(true)
+    }
+
+    /// Provides a default computed from how many times the referenced argument occurred: when
+    /// this arg isn't supplied, it takes on the occurrence count in string form (`"0"` when the
+    /// referenced arg is absent). This suits migrations where e.g. `--threads` should default
+    /// to the number of `-j` occurrences. Resolution happens during match resolution, after
+    /// occurrences are counted.
+    ///
+    /// **NOTE:** implicitly sets [`Arg::takes_value(true)`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("verbose")
+    ///         .short('v')
+    ///         .multiple_occurrences(true))
+    ///     .arg(Arg::new("level")
+    ///         .long("level")
+    ///         .takes_value(true)
+    ///         .default_value_from_count("verbose"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "-vv"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("level"), Some("2"));
+    /// ```
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    #[inline]
+    pub fn default_value_from_count<T: Key>(mut self, arg_id: T) -> Self {
+        self.default_val_from_count = Some(arg_id.into());
+        self.takes_value(true)
+    }
+
+    /// Provides a default value that is only applied when the process is attached to a
+    /// terminal, for `--progress` style options that should stay quiet when output is piped.
+    /// The check runs during match resolution and asks whether **stdin** (file descriptor 0,
+    /// `atty::Stream::Stdin`) is a TTY; if it isn't, no default is applied and the argument
+    /// simply stays absent.
+    ///
+    /// **NOTE:** Only available when the `tty_default` feature is enabled.
+    ///
+    /// **NOTE:** implicitly sets [`Arg::takes_value(true)`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// App::new("prog")
+    ///     .arg(Arg::new("progress")
+    ///         .long("progress")
+    ///         .default_value_if_tty("auto"))
+    /// # ;
+    /// ```
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    #[cfg(feature = "tty_default")]
+    #[inline]
+    pub fn default_value_if_tty(self, val: &'help str) -> Self {
+        self.default_value_if_tty_with(val, || atty::is(atty::Stream::Stdin))
+    }
+
+    /// Like [`Arg::default_value_if_tty`] but with an injectable TTY predicate, mainly useful
+    /// to test both branches without an actual terminal.
+    ///
+    /// [`Arg::default_value_if_tty`]: ./struct.Arg.html#method.default_value_if_tty
+    #[cfg(feature = "tty_default")]
+    #[inline]
+    pub fn default_value_if_tty_with(mut self, val: &'help str, is_tty: fn() -> bool) -> Self {
+        self.default_val_if_tty = Some((OsStr::new(val), is_tty));
+        self.takes_value(true)
+    }
+
     /// Like [`Arg::default_value`] but for args taking multiple values
     ///
     /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
@@ -2916,7 +4827,63 @@ impl<'help> Arg<'help> {
         ifs: &[(T, Option<&'help OsStr>, &'help OsStr)],
     ) -> Self {
         for (arg, val, default) in ifs {
-            self = self.default_value_if_os(arg.key(), *val, default);
+            self = self.default_value_if_os(arg, *val, default);
+        }
+        self
+    }
+
+    /// Provides multiple conditional default values like [`Arg::default_value_ifs`], but with
+    /// each condition spelled out as a [`DefaultWhen`] instead of an `Option` in the middle of a
+    /// tuple. The two styles are equivalent; this one reads better when mixing "if present" and
+    /// "if equals" conditions.
+    ///
+    /// # Examples
+    ///
+    /// These two declarations behave identically:
+    ///
+    /// ```rust
+    /// # use clap::{Arg, DefaultWhen};
+    /// let tuples = Arg::new("other")
+    ///     .default_value_ifs(&[
+    ///         ("flag", None, "default"),
+    ///         ("opt", Some("channal"), "chan"),
+    ///     ]);
+    ///
+    /// let spelled_out = Arg::new("other")
+    ///     .default_values_from(&[
+    ///         ("flag", DefaultWhen::Present("default")),
+    ///         ("opt", DefaultWhen::Equals("channal", "chan")),
+    ///     ]);
+    /// ```
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, DefaultWhen};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("flag")
+    ///         .long("flag"))
+    ///     .arg(Arg::new("other")
+    ///         .long("other")
+    ///         .default_values_from(&[
+    ///             ("flag", DefaultWhen::Present("default")),
+    ///         ]))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--flag"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("other"), Some("default"));
+    /// ```
+    /// [`Arg::default_value_ifs`]: ./struct.Arg.html#method.default_value_ifs
+    /// [`DefaultWhen`]: ./enum.DefaultWhen.html
+    pub fn default_values_from<T: Key>(mut self, entries: &[(T, DefaultWhen<'help>)]) -> Self {
+        for (arg, when) in entries {
+            self = match *when {
+                DefaultWhen::Present(default) => {
+                    self.default_value_if_os(arg, None, OsStr::new(default))
+                }
+                DefaultWhen::Equals(val, default) => {
+                    self.default_value_if_os(arg, Some(OsStr::new(val)), OsStr::new(default))
+                }
+            };
         }
         self
     }
@@ -2935,6 +4902,10 @@ impl<'help> Arg<'help> {
     /// - When [`Arg::takes_value(true)`] is set, [`ArgMatches::value_of`] will
     ///   return the default specified.
     ///
+    /// A value found in the environment satisfies [`Arg::required`]: combining the two means
+    /// "must come from the CLI *or* the environment", and only when neither supplies a value
+    /// does parsing fail with [`ErrorKind::MissingRequiredArgument`].
+    ///
     /// # Examples
     ///
     /// In this example, we show the variable coming from the environment:
@@ -3047,6 +5018,8 @@ impl<'help> Arg<'help> {
     /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
     /// [`Arg::multiple(true)`]: ./struct.Arg.html#method.multiple
     /// [`Arg::use_delimiter(true)`]: ./struct.Arg.html#method.use_delimiter
+    /// [`Arg::required`]: ./struct.Arg.html#method.required
+    /// [`ErrorKind::MissingRequiredArgument`]: ./enum.ErrorKind.html#variant.MissingRequiredArgument
     #[inline]
     pub fn env(self, name: &'help str) -> Self {
         self.env_os(OsStr::new(name))
@@ -3061,6 +5034,68 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// When the value comes from the environment variable set with [`Arg::env`], split it on
+    /// newlines instead of the value delimiter. CI systems commonly inject one item per line;
+    /// values given on the command line keep the normal delimiter behavior. A trailing carriage
+    /// return on a line is stripped and empty lines are skipped.
+    ///
+    /// **NOTE:** only meaningful together with [`Arg::env`] and
+    /// [`ArgSettings::MultipleValues`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// std::env::set_var("CLAP_TEST_ENV_LINES", "one\ntwo\nthree");
+    ///
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("items")
+    ///         .long("items")
+    ///         .takes_value(true)
+    ///         .multiple_values(true)
+    ///         .env("CLAP_TEST_ENV_LINES")
+    ///         .env_line_delimited(true))
+    ///     .get_matches_from(vec![
+    ///         "prog",
+    ///     ]);
+    ///
+    /// assert_eq!(m.values_of("items").unwrap().collect::<Vec<_>>(), ["one", "two", "three"]);
+    /// ```
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    /// [`ArgSettings::MultipleValues`]: ./enum.ArgSettings.html#variant.MultipleValues
+    #[inline]
+    pub fn env_line_delimited(mut self, line_delimited: bool) -> Self {
+        self.env_line_delim = line_delimited;
+        self
+    }
+
+    /// Specifies both an environment variable and a fallback value with the precedence baked
+    /// in: a value given on the command line wins, otherwise the environment variable is used
+    /// if set, otherwise `fallback`. This is exactly [`Arg::env`] combined with
+    /// [`Arg::default_value`], spelled so the two can't be accidentally reversed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("colors")
+    ///         .long("colors")
+    ///         .env_or("UNSET_CLAP_COLORS_VAR", "auto"))
+    ///     .get_matches_from(vec![
+    ///         "prog",
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("colors"), Some("auto"));
+    /// ```
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    #[inline]
+    pub fn env_or(self, var: &'help str, fallback: &'help str) -> Self {
+        self.env_os(OsStr::new(var))
+            .default_value_os(OsStr::new(fallback))
+    }
+
     /// Allows custom ordering of args within the help message. Args with a lower value will be
     /// displayed first in the help message. This is helpful when one would like to emphasise
     /// frequently used args, or prioritize those towards the top of the list. Duplicate values
@@ -3122,6 +5157,45 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// Specifies that this argument should be displayed directly before `other` in the help
+    /// message, without assigning absolute [`Arg::display_order`] numbers. The ordering is
+    /// resolved at build time by topologically ordering against the referenced argument.
+    ///
+    /// **Panics**
+    ///
+    /// Panics at build time if `other` does not exist or if the before/after constraints form
+    /// a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::new("beta").long("beta").display_order(1))
+    ///     .arg(Arg::new("alpha").long("alpha").display_after("beta"));
+    /// ```
+    /// [`Arg::display_order`]: ./struct.Arg.html#method.display_order
+    #[inline]
+    pub fn display_before(mut self, other_name: &'help str) -> Self {
+        self.display_before.push(other_name);
+        self
+    }
+
+    /// Specifies that this argument should be displayed directly after `other` in the help
+    /// message; the relative counterpart to [`Arg::display_before`].
+    ///
+    /// **Panics**
+    ///
+    /// Panics at build time if `other` does not exist or if the before/after constraints form
+    /// a cycle.
+    ///
+    /// [`Arg::display_before`]: ./struct.Arg.html#method.display_before
+    #[inline]
+    pub fn display_after(mut self, other_name: &'help str) -> Self {
+        self.display_after.push(other_name);
+        self
+    }
+
     /// Specifies that this arg is the last, or final, positional argument (i.e. has the highest
     /// index) and is *only* able to be accessed via the `--` syntax (i.e. `$ prog args --
     /// last_arg`). Even, if no other arguments are left to parse, if the user omits the `--` syntax
@@ -3210,6 +5284,37 @@ impl<'help> Arg<'help> {
         }
     }
 
+    /// Like [`Arg::last`], but the token that begins capturing trailing values is `sep` instead
+    /// of `--`. This is for tools mimicking interfaces with a custom separator such as `++`;
+    /// `--` keeps its usual meaning alongside it.
+    ///
+    /// **NOTE:** implicitly sets [`ArgSettings::Last`] and [`ArgSettings::TakesValue`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("first"))
+    ///     .arg(Arg::new("cmd")
+    ///         .multiple_values(true)
+    ///         .last_separator("++"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "one", "++", "some", "cmd"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("first"), Some("one"));
+    /// assert_eq!(m.values_of("cmd").unwrap().collect::<Vec<_>>(), ["some", "cmd"]);
+    /// ```
+    /// [`Arg::last`]: ./struct.Arg.html#method.last
+    /// [`ArgSettings::Last`]: ./enum.ArgSettings.html#variant.Last
+    /// [`ArgSettings::TakesValue`]: ./enum.ArgSettings.html#variant.TakesValue
+    #[inline]
+    pub fn last_separator(mut self, sep: &'help str) -> Self {
+        self.last_sep = Some(sep);
+        self.takes_value(true).setting(ArgSettings::Last)
+    }
+
     /// Specifies that the argument is required by default. Required by default means it is
     /// required, when no other conflicting rules or overrides have been evaluated. Conflicting
     /// rules take precedence over being required.
@@ -3313,6 +5418,15 @@ impl<'help> Arg<'help> {
     /// [`Arg::value_delimiter(char)`]: ./struct.Arg.html#method.value_delimiter
     /// [`Arg::unset_setting(ArgSettings::UseValueDelimiter)`]: ./enum.ArgSettings.html#variant.UseValueDelimiter
     /// [multiple values]: ./enum.ArgSettings.html#variant.MultipleValues
+    ///
+    /// **NOTE:** Builder methods such as [`Arg::possible_values`], [`Arg::value_name`] or
+    /// [`Arg::min_values`] imply `takes_value(true)` at the moment they are called, not at build
+    /// time. Calling `takes_value(false)` *after* them therefore always wins and the argument
+    /// stays a flag.
+    ///
+    /// [`Arg::possible_values`]: ./struct.Arg.html#method.possible_values
+    /// [`Arg::value_name`]: ./struct.Arg.html#method.value_name
+    /// [`Arg::min_values`]: ./struct.Arg.html#method.min_values
     #[inline]
     pub fn takes_value(self, tv: bool) -> Self {
         if tv {
@@ -3442,7 +5556,32 @@ impl<'help> Arg<'help> {
     /// assert!(res.is_err());
     /// assert_eq!(res.unwrap_err().kind, ErrorKind::EmptyValue);
     /// ```
+    ///
+    /// Combined with [`Arg::min_values(0)`] the value becomes optional and the space form stops
+    /// being an error: `--config` alone applies the [`Arg::default_missing_value`], `--config=val`
+    /// supplies `val`, and in `--config val` the option consumes nothing, leaving `val` to be
+    /// matched as the next argument or positional.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgSettings};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("cfg")
+    ///         .setting(ArgSettings::TakesValue)
+    ///         .setting(ArgSettings::RequireEquals)
+    ///         .min_values(0)
+    ///         .default_missing_value("default.conf")
+    ///         .long("config"))
+    ///     .arg(Arg::new("file"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--config", "file.conf"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("cfg"), Some("default.conf"));
+    /// assert_eq!(m.value_of("file"), Some("file.conf"));
+    /// ```
     /// [`RequireEquals`]: ./enum.ArgSettings.html#variant.RequireEquals
+    /// [`Arg::min_values(0)`]: ./struct.Arg.html#method.min_values
+    /// [`Arg::default_missing_value`]: ./struct.Arg.html#method.default_missing_value
     /// [`ArgSettings::EmptyValues`]: ./enum.ArgSettings.html#variant.EmptyValues
     /// [`ArgSettings::EmptyValues`]: ./enum.ArgSettings.html#variant.TakesValue
     #[inline]
@@ -3587,6 +5726,70 @@ impl<'help> Arg<'help> {
         }
     }
 
+    /// Specifies that a value (or part of one) wrapped in matching quotes (`"` or `'`) is not
+    /// split on the [value delimiter], allowing a literal delimiter inside a value:
+    /// `--tags "a,b",c` yields `["a,b", "c"]`.
+    ///
+    /// The escaping rules are deliberately small: only one pair of *outermost* matching quotes
+    /// is stripped from each piece, there are no backslash escapes, and a quote that is never
+    /// closed suppresses splitting for the rest of the value and is kept literally.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("tags")
+    ///         .long("tags")
+    ///         .use_delimiter(true)
+    ///         .allow_quoted_delimiters(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--tags", "\"a,b\",c",
+    ///     ]);
+    ///
+    /// assert_eq!(m.values_of("tags").unwrap().collect::<Vec<_>>(), ["a,b", "c"]);
+    /// ```
+    /// [value delimiter]: ./struct.Arg.html#method.value_delimiter
+    #[inline]
+    pub fn allow_quoted_delimiters(mut self, b: bool) -> Self {
+        self.quoted_delimiters = b;
+        self
+    }
+
+    /// Specifies that the [value delimiter] only splits at bracket nesting depth zero, so a
+    /// delimiter inside brackets stays part of the value: `--filter a,(b,c),d` yields
+    /// `["a", "(b,c)", "d"]`. This suits query-language-style values where parentheses carry
+    /// meaning.
+    ///
+    /// The tracked bracket pairs are `()`, `[]` and `{}`; all three share one nesting depth
+    /// counter. Brackets are kept literally, an unmatched closing bracket is ignored, and an
+    /// unclosed opening bracket suppresses splitting for the rest of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("filter")
+    ///         .long("filter")
+    ///         .use_delimiter(true)
+    ///         .balanced_delimiters(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--filter", "key in (a,b,c),other",
+    ///     ]);
+    ///
+    /// assert_eq!(
+    ///     m.values_of("filter").unwrap().collect::<Vec<_>>(),
+    ///     ["key in (a,b,c)", "other"]
+    /// );
+    /// ```
+    /// [value delimiter]: ./struct.Arg.html#method.value_delimiter
+    #[inline]
+    pub fn balanced_delimiters(mut self, b: bool) -> Self {
+        self.balanced_delimiters = b;
+        self
+    }
+
     /// Specifies if the possible values of an argument should be displayed in the help text or
     /// not. Defaults to `false` (i.e. show possible values)
     ///
@@ -3625,6 +5828,37 @@ impl<'help> Arg<'help> {
         }
     }
 
+    /// Shows the `[possible values: ...]` annotation in help only while the given environment
+    /// variable is set to a non-empty value; otherwise it is hidden as if
+    /// [`ArgSettings::HidePossibleValues`] were set. This is the possible-values counterpart of
+    /// [`Arg::hidden_unless_env`], letting release builds hide the list without recompiling.
+    /// Matching against the possible values is unaffected.
+    ///
+    /// The variable is checked when the `App` is built, just before parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("mode")
+    ///         .long("mode")
+    ///         .possible_values(&["fast", "slow"])
+    ///         .hide_possible_values_unless_env("MYAPP_INTERNAL_HELP"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--mode", "fast"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("mode"), Some("fast"));
+    /// ```
+    /// [`ArgSettings::HidePossibleValues`]: ./enum.ArgSettings.html#variant.HidePossibleValues
+    /// [`Arg::hidden_unless_env`]: ./struct.Arg.html#method.hidden_unless_env
+    #[inline]
+    pub fn hide_possible_values_unless_env(mut self, var: &'help str) -> Self {
+        self.hide_pv_unless_env = Some(var);
+        self
+    }
+
     /// Specifies that the default value of an argument should not be displayed in the help text.
     ///
     /// This is useful when default behavior of an arg is explained elsewhere in the help text.
@@ -3663,6 +5897,26 @@ impl<'help> Arg<'help> {
         }
     }
 
+    /// Hides the `[default: ...]` annotation in the help message unless the given arg is defined
+    /// in the `App` and visible in help. This is purely a rendering concern: the default value
+    /// itself still applies. If the target arg doesn't exist, the annotation stays hidden.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::new("config")
+    ///     .long("config")
+    ///     .default_value("config.toml")
+    ///     .hide_default_value_if("verbose")
+    /// # ;
+    /// ```
+    /// [`Arg::hide_default_value`]: ./struct.Arg.html#method.hide_default_value
+    pub fn hide_default_value_if<T: Key>(mut self, arg_id: T) -> Self {
+        self.hide_default_if = Some(arg_id.into());
+        self
+    }
+
     /// Hides an argument from help message output.
     ///
     /// **NOTE:** This does **not** hide the argument from usage strings on error
@@ -3710,55 +5964,241 @@ impl<'help> Arg<'help> {
         }
     }
 
-    /// When used with [`Arg::possible_values`] it allows the argument value to pass validation even
-    /// if the case differs from that of the specified `possible_value`.
+    /// Hides the argument from help messages *unless* the given environment variable is set to
+    /// a non-empty value when the `App` is built. This keeps experimental flags out of normal
+    /// help output while letting power users reveal them on demand; parsing and matching are
+    /// unaffected either way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("experimental")
+    ///         .long("experimental")
+    ///         .hidden_unless_env("MYAPP_SHOW_HIDDEN"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--experimental"
+    ///     ]);
+    ///
+    /// assert!(m.is_present("experimental"));
+    /// ```
+    #[inline]
+    pub fn hidden_unless_env(mut self, var: &'help str) -> Self {
+        self.hidden_unless_env = Some(var);
+        self
+    }
+
+    /// When used with [`Arg::possible_values`] it allows the argument value to pass validation even
+    /// if the case differs from that of the specified `possible_value`.
+    ///
+    /// **NOTE:** Setting this requires [`ArgSettings::TakesValue`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgSettings};
+    /// let m = App::new("pv")
+    ///     .arg(Arg::new("option")
+    ///         .long("--option")
+    ///         .setting(ArgSettings::TakesValue)
+    ///         .setting(ArgSettings::IgnoreCase)
+    ///         .possible_value("test123"))
+    ///     .get_matches_from(vec![
+    ///         "pv", "--option", "TeSt123",
+    ///     ]);
+    ///
+    /// assert!(m.value_of("option").unwrap().eq_ignore_ascii_case("test123"));
+    /// ```
+    ///
+    /// This setting also works when multiple values can be defined:
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgSettings};
+    /// let m = App::new("pv")
+    ///     .arg(Arg::new("option")
+    ///         .short('o')
+    ///         .long("--option")
+    ///         .setting(ArgSettings::IgnoreCase)
+    ///         .setting(ArgSettings::TakesValue)
+    ///         .setting(ArgSettings::MultipleValues)
+    ///         .possible_value("test123")
+    ///         .possible_value("test321"))
+    ///     .get_matches_from(vec![
+    ///         "pv", "--option", "TeSt123", "teST123", "tESt321"
+    ///     ]);
+    ///
+    /// let matched_vals = m.values_of("option").unwrap().collect::<Vec<_>>();
+    /// assert_eq!(&*matched_vals, &["TeSt123", "teST123", "tESt321"]);
+    /// ```
+    #[inline]
+    pub fn case_insensitive(self, ci: bool) -> Self {
+        if ci {
+            self.setting(ArgSettings::IgnoreCase)
+        } else {
+            self.unset_setting(ArgSettings::IgnoreCase)
+        }
+    }
+
+    /// Specifies that values which are not valid UTF-8 should be kept as raw `OsString`s, for
+    /// path-handling CLIs on Unix. Such values are exempt from [`AppSettings::StrictUtf8`];
+    /// retrieve them with [`ArgMatches::value_of_os`], since [`ArgMatches::value_of`] returns
+    /// `None` for a value that is not valid UTF-8 on an argument with this setting.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(unix), doc = " ```ignore")]
+    #[cfg_attr(unix, doc = " ```rust")]
+    /// # use clap::{App, Arg};
+    /// # use std::ffi::OsString;
+    /// # use std::os::unix::ffi::OsStringExt;
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("path")
+    ///         .index(1)
+    ///         .allow_invalid_utf8(true))
+    ///     .get_matches_from(vec![
+    ///         OsString::from("prog"),
+    ///         OsString::from_vec(vec![b'/', b't', b'm', b'p', b'/', 0xe9])
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("path"), None);
+    /// assert!(m.value_of_os("path").is_some());
+    /// ```
+    /// [`AppSettings::StrictUtf8`]: ./enum.AppSettings.html#variant.StrictUtf8
+    /// [`ArgMatches::value_of_os`]: ./struct.ArgMatches.html#method.value_of_os
+    /// [`ArgMatches::value_of`]: ./struct.ArgMatches.html#method.value_of
+    #[inline]
+    pub fn allow_invalid_utf8(self, tv: bool) -> Self {
+        if tv {
+            self.setting(ArgSettings::AllowInvalidUtf8)
+        } else {
+            self.unset_setting(ArgSettings::AllowInvalidUtf8)
+        }
+    }
+
+    /// When used with [`Arg::possible_values`] and [`ArgSettings::IgnoreCase`], rewrites each
+    /// matched value to the canonical casing of the `possible_value` it matched. Downstream code
+    /// then always sees the casing declared in the possible values, regardless of what the user
+    /// typed. Values that don't match any possible value are stored untouched (validation rejects
+    /// them afterwards as usual).
+    ///
+    /// **NOTE:** This has no effect unless [`ArgSettings::IgnoreCase`] is set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgSettings};
+    /// let m = App::new("pv")
+    ///     .arg(Arg::new("option")
+    ///         .long("option")
+    ///         .setting(ArgSettings::TakesValue)
+    ///         .setting(ArgSettings::IgnoreCase)
+    ///         .normalize_case(true)
+    ///         .possible_value("Fast")
+    ///         .possible_value("Slow"))
+    ///     .get_matches_from(vec![
+    ///         "pv", "--option", "fAsT",
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("option"), Some("Fast"));
+    /// ```
+    /// [`Arg::possible_values`]: ./struct.Arg.html#method.possible_values
+    /// [`ArgSettings::IgnoreCase`]: ./enum.ArgSettings.html#variant.IgnoreCase
+    #[inline]
+    pub fn normalize_case(mut self, n: bool) -> Self {
+        self.normalize_case = n;
+        self
+    }
+
+    /// Specifies that a single provided value should be split on line breaks into multiple
+    /// values, as if each line had been passed separately. A trailing carriage return is
+    /// stripped from each line (like [`str::lines`]) and empty lines are skipped, so input
+    /// ending with a newline doesn't produce a trailing empty value.
     ///
-    /// **NOTE:** Setting this requires [`ArgSettings::TakesValue`]
+    /// Unlike [`Arg::value_delimiter`] this operates on each provided value as a whole, which
+    /// makes it a good fit for values read from files or other multi-line sources.
+    ///
+    /// **NOTE:** Implicitly sets [`ArgSettings::TakesValue`] and [`ArgSettings::MultipleValues`]
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use clap::{App, Arg, ArgSettings};
-    /// let m = App::new("pv")
-    ///     .arg(Arg::new("option")
-    ///         .long("--option")
-    ///         .setting(ArgSettings::TakesValue)
-    ///         .setting(ArgSettings::IgnoreCase)
-    ///         .possible_value("test123"))
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("hosts")
+    ///         .long("hosts")
+    ///         .values_from_lines())
     ///     .get_matches_from(vec![
-    ///         "pv", "--option", "TeSt123",
+    ///         "prog", "--hosts", "alpha\nbeta\ngamma\n",
     ///     ]);
     ///
-    /// assert!(m.value_of("option").unwrap().eq_ignore_ascii_case("test123"));
+    /// let hosts: Vec<_> = m.values_of("hosts").unwrap().collect();
+    /// assert_eq!(hosts, ["alpha", "beta", "gamma"]);
     /// ```
+    /// [`str::lines`]: https://doc.rust-lang.org/std/primitive.str.html#method.lines
+    /// [`Arg::value_delimiter`]: ./struct.Arg.html#method.value_delimiter
+    /// [`ArgSettings::TakesValue`]: ./enum.ArgSettings.html#variant.TakesValue
+    /// [`ArgSettings::MultipleValues`]: ./enum.ArgSettings.html#variant.MultipleValues
+    #[inline]
+    pub fn values_from_lines(mut self) -> Self {
+        self.values_from_lines = true;
+        self.setting(ArgSettings::TakesValue)
+            .setting(ArgSettings::MultipleValues)
+    }
+
+    /// Specifies that a value of this argument beginning with `@` names a file whose
+    /// whitespace-or-newline-separated contents replace the value, following the common
+    /// `@args.txt` convention. A value beginning with `@@` is not expanded; the first `@` is
+    /// stripped and the rest is kept as a literal value starting with `@`. A file that cannot
+    /// be read produces an [`ErrorKind::Io`] error.
     ///
-    /// This setting also works when multiple values can be defined:
+    /// Expansion happens before validation, so file contents flow through
+    /// [`Arg::possible_values`] and [`Arg::validator`] checks like values given directly.
+    ///
+    /// # Examples
     ///
     /// ```rust
-    /// # use clap::{App, Arg, ArgSettings};
-    /// let m = App::new("pv")
-    ///     .arg(Arg::new("option")
-    ///         .short('o')
-    ///         .long("--option")
-    ///         .setting(ArgSettings::IgnoreCase)
-    ///         .setting(ArgSettings::TakesValue)
-    ///         .setting(ArgSettings::MultipleValues)
-    ///         .possible_value("test123")
-    ///         .possible_value("test321"))
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("input")
+    ///         .takes_value(true)
+    ///         .expand_at_files(true))
     ///     .get_matches_from(vec![
-    ///         "pv", "--option", "TeSt123", "teST123", "tESt321"
+    ///         "prog", "@@literal",
     ///     ]);
     ///
-    /// let matched_vals = m.values_of("option").unwrap().collect::<Vec<_>>();
-    /// assert_eq!(&*matched_vals, &["TeSt123", "teST123", "tESt321"]);
+    /// assert_eq!(m.value_of("input"), Some("@literal"));
     /// ```
+    /// [`ErrorKind::Io`]: ./enum.ErrorKind.html#variant.Io
+    /// [`Arg::possible_values`]: ./struct.Arg.html#method.possible_values
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
     #[inline]
-    pub fn case_insensitive(self, ci: bool) -> Self {
-        if ci {
-            self.setting(ArgSettings::IgnoreCase)
-        } else {
-            self.unset_setting(ArgSettings::IgnoreCase)
-        }
+    pub fn expand_at_files(mut self, b: bool) -> Self {
+        self.expand_at_files = b;
+        self
+    }
+
+    /// When this argument is [required] but missing from the command line and stdin is a TTY,
+    /// prints `prompt` on stderr and reads the value from one line of stdin instead of erroring.
+    /// The prompted value flows through validators like any other value. When stdin is not a TTY
+    /// (e.g. in scripts or pipelines), the usual missing-argument error is produced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::new("token")
+    ///     .long("token")
+    ///     .required(true)
+    ///     .prompt_if_missing("Enter your API token")
+    /// # ;
+    /// ```
+    /// [required]: ./struct.Arg.html#method.required
+    #[cfg(feature = "prompt")]
+    #[inline]
+    pub fn prompt_if_missing(mut self, prompt: &'help str) -> Self {
+        self.prompt_if_missing = Some(prompt);
+        self
     }
 
     /// Specifies that an argument should allow grouping of multiple values via a
@@ -3812,7 +6252,7 @@ impl<'help> Arg<'help> {
     pub fn use_delimiter(mut self, d: bool) -> Self {
         if d {
             if self.val_delim.is_none() {
-                self.val_delim = Some(',');
+                self.val_delim = Some(",");
             }
             self.takes_value(true)
                 .setting(ArgSettings::UseValueDelimiter)
@@ -4274,6 +6714,108 @@ impl<'help> Arg<'help> {
         }
     }
 
+    /// Specifies that the argument may be repeated, taking exactly one value per occurrence,
+    /// with all values appended into a single list (`-I a -I b` yields `[a, b]` while `-I a b`
+    /// is an error). This is a clearly-named shortcut for the
+    /// [`ArgSettings::MultipleOccurrences`] + [`Arg::number_of_values(1)`] combination.
+    ///
+    /// **NOTE:** implicitly sets [`Arg::takes_value(true)`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("include")
+    ///         .short('I')
+    ///         .append(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "-I", "a", "-I", "b"
+    ///     ]);
+    ///
+    /// let includes: Vec<_> = m.values_of("include").unwrap().collect();
+    /// assert_eq!(includes, ["a", "b"]);
+    /// ```
+    /// [`ArgSettings::MultipleOccurrences`]: ./enum.ArgSettings.html#variant.MultipleOccurrences
+    /// [`Arg::number_of_values(1)`]: ./struct.Arg.html#method.number_of_values
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    #[inline]
+    pub fn append(mut self, append: bool) -> Self {
+        if append {
+            self.num_vals = Some(1);
+            self.takes_value(true).multiple_occurrences(true)
+        } else {
+            self.num_vals = None;
+            self.multiple_occurrences(false)
+        }
+    }
+
+    /// Turns the argument into a repeatable flag whose occurrence count is capped at `max`,
+    /// erroring with [`ErrorKind::TooManyOccurrences`] when given more often. The count is
+    /// available through [`ArgMatches::count_of`]. This is made for `-vvv` style verbosity
+    /// flags that must not exceed a fixed level.
+    ///
+    /// **NOTE:** implicitly sets [`ArgSettings::MultipleOccurrences`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("verbose")
+    ///         .short('v')
+    ///         .count_with_max(3))
+    ///     .get_matches_from(vec![
+    ///         "prog", "-vv",
+    ///     ]);
+    ///
+    /// assert_eq!(m.count_of("verbose"), 2);
+    /// ```
+    /// [`ErrorKind::TooManyOccurrences`]: ./enum.ErrorKind.html#variant.TooManyOccurrences
+    /// [`ArgMatches::count_of`]: ./struct.ArgMatches.html#method.count_of
+    /// [`ArgSettings::MultipleOccurrences`]: ./enum.ArgSettings.html#variant.MultipleOccurrences
+    #[inline]
+    pub fn count_with_max(mut self, max: u64) -> Self {
+        self.max_occurs = Some(max);
+        self.setting(ArgSettings::MultipleOccurrences)
+    }
+
+    /// Caps how many times the argument may appear on the command line, erroring with
+    /// [`ErrorKind::TooManyOccurrences`] when exceeded. Unlike [`Arg::number_of_values`] this
+    /// counts occurrences, not values, and is independent of [`ArgSettings::MultipleValues`].
+    ///
+    /// **NOTE:** implicitly sets [`ArgSettings::MultipleOccurrences`] when `qty` is greater
+    /// than `1`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("output")
+    ///         .short('o')
+    ///         .takes_value(true)
+    ///         .max_occurrences(1))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "-o", "a.txt", "-o", "b.txt",
+    ///     ]);
+    ///
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::TooManyOccurrences);
+    /// ```
+    /// [`ErrorKind::TooManyOccurrences`]: ./enum.ErrorKind.html#variant.TooManyOccurrences
+    /// [`Arg::number_of_values`]: ./struct.Arg.html#method.number_of_values
+    /// [`ArgSettings::MultipleValues`]: ./enum.ArgSettings.html#variant.MultipleValues
+    /// [`ArgSettings::MultipleOccurrences`]: ./enum.ArgSettings.html#variant.MultipleOccurrences
+    #[inline]
+    pub fn max_occurrences(mut self, qty: u64) -> Self {
+        self.max_occurs = Some(qty);
+        if qty > 1 {
+            self.multiple_occurrences(true)
+        } else {
+            self
+        }
+    }
+
     /// Indicates that all parameters passed after this should not be parsed
     /// individually, but rather passed in their entirety. It is worth noting
     /// that setting this requires all values to come after a `--` to indicate they
@@ -4458,6 +7000,41 @@ impl<'help> Arg<'help> {
         }
     }
 
+    /// Hides the argument from both `-h` and `--help`, unless the [`App`] has
+    /// [`AppSettings::ShowHiddenArgs`] set, in which case long help (`--help`) lists it.
+    /// Unlike [`Arg::hidden`] this leaves an opt-in way to surface the argument, which is
+    /// handy for debug-only options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, Arg};
+    /// App::new("prog")
+    ///     .setting(AppSettings::ShowHiddenArgs)
+    ///     .arg(Arg::new("dump-state")
+    ///         .long("dump-state")
+    ///         .hide_unless_long_help(true)
+    ///         .about("Internal diagnostics"))
+    /// # ;
+    /// ```
+    /// [`App`]: ./struct.App.html
+    /// [`AppSettings::ShowHiddenArgs`]: ./enum.AppSettings.html#variant.ShowHiddenArgs
+    /// [`Arg::hidden`]: ./struct.Arg.html#method.hidden
+    #[inline]
+    pub fn hide_unless_long_help(mut self, hide: bool) -> Self {
+        self.hide_unless_long_help = hide;
+        self
+    }
+
+    /// Get whether [`Arg::hide_unless_long_help`] was set, so external help renderers can
+    /// honor the same visibility rule.
+    ///
+    /// [`Arg::hide_unless_long_help`]: ./struct.Arg.html#method.hide_unless_long_help
+    #[inline]
+    pub fn get_hide_unless_long_help(&self) -> bool {
+        self.hide_unless_long_help
+    }
+
     // @TODO @docs @v3-beta: write better docs as ArgSettings is now critical
     /// Checks if one of the [`ArgSettings`] is set for the argument
     ///
@@ -4506,10 +7083,141 @@ impl<'help> Arg<'help> {
         self
     }
 
-    /// Set a custom heading for this arg to be printed under
+    /// Clears *every* setting on this argument, returning the [`ArgSettings`] bitset to its
+    /// default (empty) state. This is useful when specializing an argument cloned from a shared
+    /// template whose settings shouldn't carry over.
+    ///
+    /// **NOTE:** Only settings are cleared; `name`, `short`, `long` and all other builder state
+    /// remain untouched
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{Arg, ArgSettings};
+    /// let base = Arg::new("config")
+    ///     .setting(ArgSettings::Required)
+    ///     .setting(ArgSettings::TakesValue);
+    /// let specialized = base.clone().reset_settings();
+    ///
+    /// assert!(!specialized.is_set(ArgSettings::Required));
+    /// assert!(!specialized.is_set(ArgSettings::TakesValue));
+    /// ```
+    /// [`ArgSettings`]: ./enum.ArgSettings.html
+    #[inline]
+    pub fn reset_settings(mut self) -> Self {
+        self.settings = ArgFlags::default();
+        self
+    }
+
+    /// Removes all [conflicts] previously declared for this argument. Like
+    /// [`Arg::reset_settings`], this is aimed at programmatic builders that clone a base argument
+    /// and need to re-declare its relationships; `name`, `short` and `long` are untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::new("config")
+    ///     .conflicts_with("debug")
+    ///     .clear_conflicts()
+    /// # ;
+    /// ```
+    /// [conflicts]: ./struct.Arg.html#method.conflicts_with
+    /// [`Arg::reset_settings`]: ./struct.Arg.html#method.reset_settings
+    #[inline]
+    pub fn clear_conflicts(mut self) -> Self {
+        self.blacklist.clear();
+        self
+    }
+
+    /// Removes all [requirements] previously declared for this argument, including conditional
+    /// ones added via [`Arg::requires_if`] and friends; `name`, `short` and `long` are untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::new("config")
+    ///     .requires("input")
+    ///     .clear_requires()
+    /// # ;
+    /// ```
+    /// [requirements]: ./struct.Arg.html#method.requires
+    /// [`Arg::requires_if`]: ./struct.Arg.html#method.requires_if
+    #[inline]
+    pub fn clear_requires(mut self) -> Self {
+        self.requires.clear();
+        self
+    }
+
+    /// Set a custom heading for this arg to be printed under. A heading set here is considered
+    /// explicit, though adding the arg inside an active [`App::help_heading`] section still
+    /// replaces it with the section's (inherited) heading.
+    ///
+    /// Both borrowed `&'help str` and owned `String` headings are accepted, so headings
+    /// computed at runtime don't need to be leaked:
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// let plugin = "git";
+    /// let arg = Arg::new("exec")
+    ///     .help_heading(Some(format!("Options for {}", plugin)));
+    /// assert_eq!(Some("Options for git"), arg.get_help_heading());
+    /// ```
+    ///
+    /// [`App::help_heading`]: ./struct.App.html#method.help_heading
+    #[inline]
+    pub fn help_heading<S>(mut self, s: Option<S>) -> Self
+    where
+        S: Into<Cow<'help, str>>,
+    {
+        self.help_heading = s.map(Into::into);
+        self.help_heading_explicit = true;
+        self
+    }
+
+    /// Set the sort order of the custom [help heading] this arg belongs to. Custom headings
+    /// are rendered by ascending order, where each heading sorts by the *minimum* order set
+    /// among its member args, so setting it on a single member is enough to place the whole
+    /// section. Headings whose members carry no order are rendered last, in the order the
+    /// args were defined.
+    ///
+    /// **NOTE:** This only has an effect in combination with [help heading].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::new("port")
+    ///     .long("port")
+    ///     .help_heading(Some("NETWORKING"))
+    ///     .help_heading_order(1)
+    /// # ;
+    /// ```
+    /// [help heading]: ./struct.Arg.html#method.help_heading
+    #[inline]
+    pub fn help_heading_order(mut self, order: usize) -> Self {
+        self.help_heading_order = Some(order);
+        self
+    }
+
+    /// Specifies that the custom heading of this arg should be rendered in uppercase. Headings
+    /// which only differ in case are normalized before grouping, so `Network`, `NETWORK`, and
+    /// `network` coalesce into a single `NETWORK` group in the help message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::new("port")
+    ///     .long("port")
+    ///     .help_heading(Some("network"))
+    ///     .uppercase_help_heading(true)
+    /// # ;
+    /// ```
     #[inline]
-    pub fn help_heading(mut self, s: Option<&'help str>) -> Self {
-        self.help_heading = s;
+    pub fn uppercase_help_heading(mut self, upper: bool) -> Self {
+        self.uppercase_help_heading = upper;
         self
     }
 
@@ -4549,15 +7257,48 @@ impl<'help> Arg<'help> {
     // FIXME: (@CreepySkeleton)
     #[doc(hidden)]
     pub fn _build(&mut self) {
+        if let Some((name, ref mut val)) = self.env {
+            // The builder captures the variable when `Arg::env` is called, which can go stale
+            // if the environment changes before parsing; re-read it now that we are parsing
+            *val = env::var_os(name);
+        }
+        if let Some(var) = self.hidden_unless_env {
+            let revealed = env::var_os(var).is_some_and(|v| !v.is_empty());
+            if !revealed {
+                self.settings.set(ArgSettings::Hidden);
+            }
+        }
+        if let Some(var) = self.hide_pv_unless_env {
+            let revealed = env::var_os(var).is_some_and(|v| !v.is_empty());
+            if !revealed {
+                self.settings.set(ArgSettings::HidePossibleValues);
+            }
+        }
+        if self.short_case_insensitive {
+            if let Some(s) = self.short {
+                if s.is_alphabetic() {
+                    for flipped in s.to_lowercase().chain(s.to_uppercase()) {
+                        if flipped != s && !self.short_aliases.iter().any(|(c, _)| *c == flipped) {
+                            self.short_aliases.push((flipped, false));
+                        }
+                    }
+                }
+            }
+        }
         if (self.is_set(ArgSettings::UseValueDelimiter)
             || self.is_set(ArgSettings::RequireDelimiter))
             && self.val_delim.is_none()
         {
-            self.val_delim = Some(',');
+            self.val_delim = Some(",");
+        }
+        if self.is_set(ArgSettings::ValueOptional) && self.min_vals.is_none() {
+            self.min_vals = Some(0);
         }
         if !(self.index.is_some() || (self.short.is_none() && self.long.is_none()))
             && self.is_set(ArgSettings::TakesValue)
+            && !self.val_labels
             && self.val_names.len() > 1
+            && self.num_vals.is_none()
         {
             self.num_vals = Some(self.val_names.len());
         }
@@ -4575,6 +7316,29 @@ impl<'help> Arg<'help> {
         self.long.is_none() && self.short.is_none()
     }
 
+    /// Applies the `auto_uppercase_value_name` and `value_name_case_by_kind` transforms when
+    /// rendering a value name; the stored literal is never modified.
+    pub(crate) fn render_val_name<'n>(&self, name: &'n str) -> Cow<'n, str> {
+        let name = if self.auto_uppercase_value_name {
+            let upper = name.to_uppercase();
+            if upper == name {
+                Cow::Borrowed(name)
+            } else {
+                Cow::Owned(upper)
+            }
+        } else if !self.value_name_case_by_kind {
+            Cow::Borrowed(name)
+        } else if self.is_positional() {
+            Cow::Owned(name.to_lowercase())
+        } else {
+            Cow::Owned(name.to_uppercase())
+        };
+        match self.val_range {
+            Some((min, max)) => Cow::Owned(format!("{}:{}-{}", name, min, max)),
+            None => name,
+        }
+    }
+
     // Used for positionals when printing
     pub(crate) fn multiple_str(&self) -> &str {
         // FIXME: This should probably be > 1
@@ -4592,12 +7356,11 @@ impl<'help> Arg<'help> {
     // Used for positionals when printing
     pub(crate) fn name_no_brackets(&self) -> Cow<str> {
         debug!("Arg::name_no_brackets:{}", self.name);
-        let mut delim = String::new();
-        delim.push(if self.is_set(ArgSettings::RequireDelimiter) {
+        let delim = if self.is_set(ArgSettings::RequireDelimiter) {
             self.val_delim.expect(INTERNAL_ERROR_MSG)
         } else {
-            ' '
-        });
+            " "
+        };
         if !self.val_names.is_empty() {
             debug!("Arg::name_no_brackets: val_names={:#?}", self.val_names);
 
@@ -4605,16 +7368,16 @@ impl<'help> Arg<'help> {
                 Cow::Owned(
                     self.val_names
                         .values()
-                        .map(|n| format!("<{}>", n))
+                        .map(|n| format!("<{}>", self.render_val_name(n)))
                         .collect::<Vec<_>>()
-                        .join(&*delim),
+                        .join(delim),
                 )
             } else {
-                Cow::Borrowed(self.val_names.values().next().expect(INTERNAL_ERROR_MSG))
+                self.render_val_name(self.val_names.values().next().expect(INTERNAL_ERROR_MSG))
             }
         } else {
             debug!("Arg::name_no_brackets: just name");
-            Cow::Borrowed(self.name)
+            self.render_val_name(self.name)
         }
     }
 }
@@ -4749,24 +7512,23 @@ impl<'help> Display for Arg<'help> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         if self.index.is_some() || (self.long.is_none() && self.short.is_none()) {
             // Positional
-            let mut delim = String::new();
-            delim.push(if self.is_set(ArgSettings::RequireDelimiter) {
+            let delim = if self.is_set(ArgSettings::RequireDelimiter) {
                 self.val_delim.expect(INTERNAL_ERROR_MSG)
             } else {
-                ' '
-            });
+                " "
+            };
             if !self.val_names.is_empty() {
                 write!(
                     f,
                     "{}",
                     self.val_names
                         .values()
-                        .map(|n| format!("<{}>", n))
+                        .map(|n| format!("<{}>", self.render_val_name(n)))
                         .collect::<Vec<_>>()
-                        .join(&*delim)
+                        .join(delim)
                 )?;
             } else {
-                write!(f, "<{}>", self.name)?;
+                write!(f, "<{}>", self.render_val_name(self.name))?;
             }
             if self.settings.is_set(ArgSettings::MultipleValues) && self.val_names.len() < 2 {
                 write!(f, "...")?;
@@ -4774,7 +7536,7 @@ impl<'help> Display for Arg<'help> {
             return Ok(());
         } else if !self.is_set(ArgSettings::TakesValue) {
             // Flag
-            if let Some(l) = self.long {
+            if let Some(l) = self.long.as_deref() {
                 write!(f, "--{}", l)?;
             } else if let Some(s) = self.short {
                 write!(f, "-{}", s)?;
@@ -4782,13 +7544,17 @@ impl<'help> Display for Arg<'help> {
 
             return Ok(());
         }
-        let sep = if self.is_set(ArgSettings::RequireEquals) {
+        // A value is optional either via the explicit setting or through `min_values(0)`
+        let value_optional = self.is_set(ArgSettings::ValueOptional) || self.min_vals == Some(0);
+        let sep = if value_optional {
+            "[="
+        } else if self.is_set(ArgSettings::RequireEquals) {
             "="
         } else {
             " "
         };
         // Write the name such --long or -l
-        if let Some(l) = self.long {
+        if let Some(l) = self.long.as_deref() {
             write!(f, "--{}{}", l, sep)?;
         } else {
             write!(f, "-{}{}", self.short.unwrap(), sep)?;
@@ -4796,26 +7562,26 @@ impl<'help> Display for Arg<'help> {
         let delim = if self.is_set(ArgSettings::RequireDelimiter) {
             self.val_delim.expect(INTERNAL_ERROR_MSG)
         } else {
-            ' '
+            " "
         };
 
         // Write the values such as <name1> <name2>
         if !self.val_names.is_empty() {
             let mut it = self.val_names.iter().peekable();
             while let Some((_, val)) = it.next() {
-                write!(f, "<{}>", val)?;
+                write!(f, "<{}>", self.render_val_name(val))?;
                 if it.peek().is_some() {
                     write!(f, "{}", delim)?;
                 }
             }
             let num = self.val_names.len();
-            if self.is_set(ArgSettings::MultipleValues) && num == 1 {
+            if self.is_set(ArgSettings::MultipleValues) && (num == 1 || self.val_labels) {
                 write!(f, "...")?;
             }
         } else if let Some(num) = self.num_vals {
             let mut it = (0..num).peekable();
             while let Some(_) = it.next() {
-                write!(f, "<{}>", self.name)?;
+                write!(f, "<{}>", self.render_val_name(self.name))?;
                 if it.peek().is_some() {
                     write!(f, "{}", delim)?;
                 }
@@ -4827,7 +7593,7 @@ impl<'help> Display for Arg<'help> {
             write!(
                 f,
                 "<{}>{}",
-                self.name,
+                self.render_val_name(self.name),
                 if self.is_set(ArgSettings::MultipleOccurrences) {
                     "..."
                 } else {
@@ -4836,6 +7602,10 @@ impl<'help> Display for Arg<'help> {
             )?;
         }
 
+        if value_optional {
+            write!(f, "]")?;
+        }
+
         Ok(())
     }
 }
@@ -4865,18 +7635,46 @@ impl<'help> fmt::Debug for Arg<'help> {
             .field("blacklist", &self.blacklist)
             .field("settings", &self.settings)
             .field("overrides", &self.overrides)
+            .field("overrides_everything", &self.overrides_everything)
+            .field("allow_self_override", &self.allow_self_override)
+            .field("normalize_case", &self.normalize_case)
+            .field("possible_val_aliases", &self.possible_val_aliases)
+            .field("show_pv_aliases", &self.show_pv_aliases)
+            .field("value_name_case_by_kind", &self.value_name_case_by_kind)
+            .field("auto_uppercase_value_name", &self.auto_uppercase_value_name)
+            .field("hide_default_if", &self.hide_default_if)
+            .field("values_from_lines", &self.values_from_lines)
+            .field("validation_exit_code", &self.validation_exit_code)
+            .field("expand_at_files", &self.expand_at_files)
+            .field("interpolate_help", &self.interpolate_help)
+            .field("max_occurs", &self.max_occurs)
+            .field("deprecated", &self.deprecated)
+            .field("blacklisted_subcommands", &self.blacklisted_subcommands)
+            .field("key_val_delim", &self.key_val_delim)
+            .field("quoted_delimiters", &self.quoted_delimiters)
+            .field("balanced_delimiters", &self.balanced_delimiters)
+            .field("id_explicit", &self.id_explicit)
             .field("groups", &self.groups)
             .field("requires", &self.requires)
+            .field("requires_explicit", &self.requires_explicit)
             .field("r_ifs", &self.r_ifs)
             .field("r_unless", &self.r_unless)
             .field("short", &self.short)
             .field("long", &self.long)
             .field("aliases", &self.aliases)
             .field("short_aliases", &self.short_aliases)
+            .field("short_case_insensitive", &self.short_case_insensitive)
             .field("disp_ord", &self.disp_ord)
+            .field("display_before", &self.display_before)
+            .field("display_after", &self.display_after)
             .field("unified_ord", &self.unified_ord)
+            .field("validate_before_possible_vals", &self.validate_before_possible_vals)
+            .field("validate_last_only", &self.validate_last_only)
             .field("possible_vals", &self.possible_vals)
+            .field("possible_vals_help", &self.possible_vals_help)
             .field("val_names", &self.val_names)
+            .field("val_labels", &self.val_labels)
+            .field("val_range", &self.val_range)
             .field("num_vals", &self.num_vals)
             .field("max_vals", &self.max_vals)
             .field("min_vals", &self.min_vals)
@@ -4888,13 +7686,71 @@ impl<'help> fmt::Debug for Arg<'help> {
                 "validator_os",
                 &self.validator_os.as_ref().map_or("None", |_| "Some(FnMut)"),
             )
+            .field(
+                "validator_all",
+                &self
+                    .validator_all
+                    .as_ref()
+                    .map_or("None", |_| "Some(FnMut)"),
+            )
+            .field(
+                "validator_suggestions",
+                &self
+                    .validator_suggestions
+                    .as_ref()
+                    .map_or("None", |_| "Some(FnMut)"),
+            )
+            .field(
+                "validator_named",
+                &self
+                    .validator_named
+                    .as_ref()
+                    .map_or("None", |_| "Some(FnMut)"),
+            )
+            .field(
+                "validator_matches",
+                &self
+                    .validator_matches
+                    .as_ref()
+                    .map_or("None", |_| "Some(FnMut)"),
+            )
+            .field(
+                "validator_warn",
+                &self
+                    .validator_warn
+                    .as_ref()
+                    .map_or("None", |_| "Some(FnMut)"),
+            )
+            .field(
+                "value_mapper",
+                &self.value_mapper.as_ref().map_or("None", |_| "Some(FnMut)"),
+            )
+            .field(
+                "value_mapper_os",
+                &self
+                    .value_mapper_os
+                    .as_ref()
+                    .map_or("None", |_| "Some(FnMut)"),
+            )
             .field("val_delim", &self.val_delim)
             .field("default_vals", &self.default_vals)
+            .field("default_val_from", &self.default_val_from)
+            .field("default_val_from_count", &self.default_val_from_count)
             .field("default_vals_ifs", &self.default_vals_ifs)
             .field("env", &self.env)
+            .field("env_line_delim", &self.env_line_delim)
             .field("terminator", &self.terminator)
+            .field("keep_terminator", &self.keep_terminator)
+            .field("last_sep", &self.last_sep)
             .field("index", &self.index)
             .field("help_heading", &self.help_heading)
+            .field("help_heading_explicit", &self.help_heading_explicit)
+            .field("uppercase_help_heading", &self.uppercase_help_heading)
+            .field("help_heading_order", &self.help_heading_order)
+            .field("hidden_unless_env", &self.hidden_unless_env)
+            .field("hide_pv_unless_env", &self.hide_pv_unless_env)
+            .field("hide_unless_long_help", &self.hide_unless_long_help)
+            .field("value_unit", &self.value_unit)
             .field("global", &self.global)
             .field("exclusive", &self.exclusive)
             .field("value_hint", &self.value_hint)
@@ -4907,6 +7763,8 @@ impl<'help> fmt::Debug for Arg<'help> {
 #[cfg(test)]
 mod test {
     use super::Arg;
+    use std::borrow::Cow;
+
     use crate::build::ArgSettings;
     use crate::util::VecMap;
 
@@ -4914,7 +7772,7 @@ mod test {
     fn flag_display() {
         let mut f = Arg::new("flg");
         f.settings.set(ArgSettings::MultipleOccurrences);
-        f.long = Some("flag");
+        f.long = Some(Cow::Borrowed("flag"));
 
         assert_eq!(&*format!("{}", f), "--flag");
 
@@ -4927,7 +7785,7 @@ mod test {
     #[test]
     fn flag_display_single_alias() {
         let mut f = Arg::new("flg");
-        f.long = Some("flag");
+        f.long = Some(Cow::Borrowed("flag"));
         f.aliases = vec![("als", true)];
 
         assert_eq!(&*format!("{}", f), "--flag")
@@ -4993,6 +7851,20 @@ mod test {
         assert_eq!(&*format!("{}", o2), "-o <file> <name>");
     }
 
+    #[test]
+    fn option_display_optional_value_min_values_zero() {
+        let o = Arg::new("opt").long("option").min_values(0);
+
+        assert_eq!(&*format!("{}", o), "--option[=<opt>]");
+    }
+
+    #[test]
+    fn option_display_required_value_min_values_nonzero() {
+        let o = Arg::new("opt").long("option").min_values(1);
+
+        assert_eq!(&*format!("{}", o), "--option <opt>");
+    }
+
     #[test]
     fn option_display_single_alias() {
         let o = Arg::new("opt")
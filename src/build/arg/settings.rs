@@ -28,6 +28,9 @@ bitflags! {
         const HIDDEN_LONG_H    = 1 << 19;
         const MULTIPLE_VALS    = 1 << 20;
         const HIDE_ENV         = 1 << 21;
+        const VALUE_OPTIONAL   = 1 << 22;
+        const REQ_INCREASING   = 1 << 23;
+        const ALLOW_BAD_UTF8   = 1 << 24;
     }
 }
 
@@ -55,7 +58,10 @@ impl_settings! { ArgSettings, ArgFlags,
     HideEnvValues("hideenvvalues") => Flags::HIDE_ENV_VALS,
     HideDefaultValue("hidedefaultvalue") => Flags::HIDE_DEFAULT_VAL,
     HiddenShortHelp("hiddenshorthelp") => Flags::HIDDEN_SHORT_H,
-    HiddenLongHelp("hiddenlonghelp") => Flags::HIDDEN_LONG_H
+    HiddenLongHelp("hiddenlonghelp") => Flags::HIDDEN_LONG_H,
+    ValueOptional("valueoptional") => Flags::VALUE_OPTIONAL,
+    RequireIncreasingValues("requireincreasingvalues") => Flags::REQ_INCREASING,
+    AllowInvalidUtf8("allowinvalidutf8") => Flags::ALLOW_BAD_UTF8
 }
 
 impl Default for ArgFlags {
@@ -115,10 +121,49 @@ pub enum ArgSettings {
     HiddenShortHelp,
     /// The argument should **not** be shown in long help text
     HiddenLongHelp,
+    /// The argument may appear with or without a value
+    ValueOptional,
+    /// Requires that multiple values parse as numbers and strictly increase
+    RequireIncreasingValues,
+    /// The argument may take values that are not valid UTF-8; use
+    /// [`ArgMatches::value_of_os`] to retrieve them
+    ///
+    /// [`ArgMatches::value_of_os`]: ./struct.ArgMatches.html#method.value_of_os
+    AllowInvalidUtf8,
     #[doc(hidden)]
     RequiredUnlessAll,
 }
 
+impl ArgSettings {
+    /// Every known setting, in enum declaration order; `Arg::get_settings` relies on this
+    /// order being stable
+    pub(crate) const ALL: [ArgSettings; 23] = [
+        ArgSettings::Required,
+        ArgSettings::MultipleValues,
+        ArgSettings::MultipleOccurrences,
+        ArgSettings::AllowEmptyValues,
+        ArgSettings::Hidden,
+        ArgSettings::TakesValue,
+        ArgSettings::UseValueDelimiter,
+        ArgSettings::NextLineHelp,
+        ArgSettings::RequireDelimiter,
+        ArgSettings::HidePossibleValues,
+        ArgSettings::AllowHyphenValues,
+        ArgSettings::RequireEquals,
+        ArgSettings::Last,
+        ArgSettings::HideDefaultValue,
+        ArgSettings::IgnoreCase,
+        ArgSettings::HideEnv,
+        ArgSettings::HideEnvValues,
+        ArgSettings::HiddenShortHelp,
+        ArgSettings::HiddenLongHelp,
+        ArgSettings::ValueOptional,
+        ArgSettings::RequireIncreasingValues,
+        ArgSettings::AllowInvalidUtf8,
+        ArgSettings::RequiredUnlessAll,
+    ];
+}
+
 #[cfg(test)]
 mod test {
     use super::ArgSettings;
@@ -194,6 +239,34 @@ mod test {
             "hiddenlonghelp".parse::<ArgSettings>().unwrap(),
             ArgSettings::HiddenLongHelp
         );
+        assert_eq!(
+            "valueoptional".parse::<ArgSettings>().unwrap(),
+            ArgSettings::ValueOptional
+        );
+        assert_eq!(
+            "requireincreasingvalues".parse::<ArgSettings>().unwrap(),
+            ArgSettings::RequireIncreasingValues
+        );
+        assert_eq!(
+            "allowinvalidutf8".parse::<ArgSettings>().unwrap(),
+            ArgSettings::AllowInvalidUtf8
+        );
         assert!("hahahaha".parse::<ArgSettings>().is_err());
     }
+
+    #[test]
+    fn arg_get_settings_declaration_order() {
+        use crate::Arg;
+
+        let arg = Arg::new("t").required(true).takes_value(true).last(true);
+        assert_eq!(
+            arg.get_settings(),
+            vec![
+                ArgSettings::Required,
+                ArgSettings::TakesValue,
+                ArgSettings::Last
+            ]
+        );
+        assert_eq!(Arg::new("t").get_settings(), vec![]);
+    }
 }
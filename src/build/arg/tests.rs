@@ -5,8 +5,8 @@ fn short_flag_misspel() {
     let a = Arg::from("-f1, --flag 'some flag'");
     assert_eq!(a.name, "flag");
     assert_eq!(a.short.unwrap(), 'f');
-    assert_eq!(a.long.unwrap(), "flag");
-    assert_eq!(a.about.unwrap(), "some flag");
+    assert_eq!(a.long.as_deref().unwrap(), "flag");
+    assert_eq!(a.about.as_deref().unwrap(), "some flag");
     assert!(!a.is_set(ArgSettings::MultipleOccurrences));
     assert!(a.val_names.is_empty());
     assert!(a.num_vals.is_none());
@@ -18,7 +18,7 @@ fn short_flag_name_missing() {
     assert_eq!(a.name, "f");
     assert_eq!(a.short.unwrap(), 'f');
     assert!(a.long.is_none());
-    assert_eq!(a.about.unwrap(), "some flag");
+    assert_eq!(a.about.as_deref().unwrap(), "some flag");
     assert!(!a.is_set(ArgSettings::MultipleOccurrences));
     assert!(a.val_names.is_empty());
     assert!(a.num_vals.is_none());
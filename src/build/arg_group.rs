@@ -113,6 +113,12 @@ impl<'help> ArgGroup<'help> {
         }
     }
 
+    /// Get the name of the group
+    #[inline]
+    pub fn get_name(&self) -> &str {
+        self.name
+    }
+
     /// Adds an [argument] to this group by name
     ///
     /// # Examples
@@ -14,6 +14,7 @@ enum UsageToken {
     Multiple,
     Unknown,
     Default,
+    Delimiter,
 }
 
 #[derive(Debug)]
@@ -56,6 +57,7 @@ impl<'help> UsageParser<'help> {
                 match c {
                     b'-' => self.short_or_long(&mut arg),
                     b'.' => self.multiple(&mut arg),
+                    b'{' => self.delimiter(&mut arg),
                     b'@' => self.default(&mut arg),
                     b'\'' => self.help(&mut arg),
                     _ => self.name(&mut arg),
@@ -152,7 +154,7 @@ impl<'help> UsageParser<'help> {
             arg.name = name;
         }
         debug!("UsageParser::long: setting long...{}", name);
-        arg.long = Some(name);
+        arg.long = Some(name.into());
         self.prev = UsageToken::Long;
     }
 
@@ -194,6 +196,21 @@ impl<'help> UsageParser<'help> {
         }
     }
 
+    // "something{,}"
+    fn delimiter(&mut self, arg: &mut Arg<'help>) {
+        debug!("UsageParser::delimiter");
+        self.pos += 1; // Skip {
+        let mut chars = self.usage[self.pos..].chars();
+        if let (Some(delim), Some('}')) = (chars.next(), chars.next()) {
+            debug!("UsageParser::delimiter: setting delimiter...{:?}", delim);
+            arg.settings.set(ArgSettings::TakesValue);
+            arg.settings.set(ArgSettings::UseValueDelimiter);
+            arg.val_delim = Some(&self.usage[self.pos..self.pos + delim.len_utf8()]);
+            self.pos += delim.len_utf8() + 1;
+            self.prev = UsageToken::Delimiter;
+        }
+    }
+
     fn help(&mut self, arg: &mut Arg<'help>) {
         debug!("UsageParser::help");
         self.stop_at(help_start);
@@ -203,7 +220,7 @@ impl<'help> UsageParser<'help> {
             "UsageParser::help: setting help...{}",
             &self.usage[self.start..self.pos]
         );
-        arg.about = Some(&self.usage[self.start..self.pos]);
+        arg.about = Some(self.usage[self.start..self.pos].into());
         self.pos += 1; // Move to next byte to keep from thinking ending ' is a start
         self.prev = UsageToken::Help;
     }
@@ -232,7 +249,7 @@ fn name_end(b: u8) -> bool {
 
 #[inline]
 fn token(b: u8) -> bool {
-    b != b'\'' && b != b'.' && b != b'<' && b != b'[' && b != b'-' && b != b'@'
+    b != b'\'' && b != b'.' && b != b'<' && b != b'[' && b != b'-' && b != b'@' && b != b'{'
 }
 
 #[inline]
@@ -261,25 +278,25 @@ mod test {
         assert_eq!(a.name, "flag");
         assert_eq!(a.short.unwrap(), 'f');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.val_names.is_empty());
         assert!(a.num_vals.is_none());
 
         let a = Arg::from("[flag] --flag 'some help info'");
         assert_eq!(a.name, "flag");
-        assert_eq!(a.long.unwrap(), "flag");
+        assert_eq!(a.long.as_deref().unwrap(), "flag");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.val_names.is_empty());
         assert!(a.num_vals.is_none());
 
         let a = Arg::from("--flag 'some help info'");
         assert_eq!(a.name, "flag");
-        assert_eq!(a.long.unwrap(), "flag");
+        assert_eq!(a.long.as_deref().unwrap(), "flag");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.val_names.is_empty());
         assert!(a.num_vals.is_none());
@@ -287,8 +304,8 @@ mod test {
         let a = Arg::from("[flag] -f --flag 'some help info'");
         assert_eq!(a.name, "flag");
         assert_eq!(a.short.unwrap(), 'f');
-        assert_eq!(a.long.unwrap(), "flag");
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.long.as_deref().unwrap(), "flag");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.val_names.is_empty());
         assert!(a.num_vals.is_none());
@@ -297,38 +314,38 @@ mod test {
         assert_eq!(a.name, "flag");
         assert_eq!(a.short.unwrap(), 'f');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.val_names.is_empty());
         assert!(a.num_vals.is_none());
 
         let a = Arg::from("[flag] -f --flag... 'some help info'");
         assert_eq!(a.name, "flag");
-        assert_eq!(a.long.unwrap(), "flag");
+        assert_eq!(a.long.as_deref().unwrap(), "flag");
         assert_eq!(a.short.unwrap(), 'f');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.val_names.is_empty());
         assert!(a.num_vals.is_none());
 
         let a = Arg::from("-f --flag... 'some help info'");
         assert_eq!(a.name, "flag");
-        assert_eq!(a.long.unwrap(), "flag");
+        assert_eq!(a.long.as_deref().unwrap(), "flag");
         assert_eq!(a.short.unwrap(), 'f');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.val_names.is_empty());
         assert!(a.num_vals.is_none());
 
         let a = Arg::from("--flags");
         assert_eq!(a.name, "flags");
-        assert_eq!(a.long.unwrap(), "flags");
+        assert_eq!(a.long.as_deref().unwrap(), "flags");
         assert!(a.val_names.is_empty());
         assert!(a.num_vals.is_none());
 
         let a = Arg::from("--flags...");
         assert_eq!(a.name, "flags");
-        assert_eq!(a.long.unwrap(), "flags");
+        assert_eq!(a.long.as_deref().unwrap(), "flags");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.val_names.is_empty());
         assert!(a.num_vals.is_none());
@@ -350,7 +367,7 @@ mod test {
         assert_eq!(a.name, "f");
         assert_eq!(a.short.unwrap(), 'f');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.val_names.is_empty());
         assert!(a.num_vals.is_none());
@@ -376,7 +393,7 @@ mod test {
         assert_eq!(a.name, "option");
         assert_eq!(a.short.unwrap(), 'o');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(!a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -391,7 +408,7 @@ mod test {
         assert_eq!(a.name, "o");
         assert_eq!(a.short.unwrap(), 'o');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(!a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -406,7 +423,7 @@ mod test {
         assert_eq!(a.name, "option");
         assert_eq!(a.short.unwrap(), 'o');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(!a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -421,7 +438,7 @@ mod test {
         assert_eq!(a.name, "o");
         assert_eq!(a.short.unwrap(), 'o');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(!a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -436,7 +453,7 @@ mod test {
         assert_eq!(a.name, "option");
         assert_eq!(a.short.unwrap(), 'o');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -451,7 +468,7 @@ mod test {
         assert_eq!(a.name, "option");
         assert_eq!(a.short.unwrap(), 'o');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::TakesValue));
         assert!(!a.is_set(ArgSettings::Required));
@@ -465,7 +482,7 @@ mod test {
         assert_eq!(a.name, "o");
         assert_eq!(a.short.unwrap(), 'o');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -480,7 +497,7 @@ mod test {
         assert_eq!(a.name, "option");
         assert_eq!(a.short.unwrap(), 'o');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -495,7 +512,7 @@ mod test {
         assert_eq!(a.name, "option");
         assert_eq!(a.short.unwrap(), 'o');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::TakesValue));
         assert!(a.is_set(ArgSettings::Required));
@@ -509,7 +526,7 @@ mod test {
         assert_eq!(a.name, "o");
         assert_eq!(a.short.unwrap(), 'o');
         assert!(a.long.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -522,9 +539,9 @@ mod test {
     fn create_option_usage_long1() {
         let a = Arg::from("[option] --opt [opt] 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(!a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -537,9 +554,9 @@ mod test {
     fn create_option_usage_long2() {
         let a = Arg::from("--opt [option] 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(!a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -552,9 +569,9 @@ mod test {
     fn create_option_usage_long3() {
         let a = Arg::from("<option> --opt <opt> 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(!a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -567,9 +584,9 @@ mod test {
     fn create_option_usage_long4() {
         let a = Arg::from("--opt <option> 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(!a.is_set(ArgSettings::MultipleOccurrences));
         assert!(!a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -582,9 +599,9 @@ mod test {
     fn create_option_usage_long5() {
         let a = Arg::from("[option] --opt [opt]... 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -597,9 +614,9 @@ mod test {
     fn create_option_usage_long6() {
         let a = Arg::from("[option]... --opt [opt] 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::TakesValue));
         assert!(!a.is_set(ArgSettings::Required));
@@ -611,9 +628,9 @@ mod test {
     fn create_option_usage_long7() {
         let a = Arg::from("--opt [option]... 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -626,9 +643,9 @@ mod test {
     fn create_option_usage_long8() {
         let a = Arg::from("<option> --opt <opt>... 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::MultipleValues));
         assert!(a.is_set(ArgSettings::TakesValue));
@@ -641,9 +658,9 @@ mod test {
     fn create_option_usage_long9() {
         let a = Arg::from("<option>... --opt <opt> 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::TakesValue));
         assert!(a.is_set(ArgSettings::Required));
@@ -655,9 +672,9 @@ mod test {
     fn create_option_usage_long10() {
         let a = Arg::from("--opt <option>... 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -671,9 +688,9 @@ mod test {
     fn create_option_usage_long_equals1() {
         let a = Arg::from("[option] --opt=[opt] 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -687,9 +704,9 @@ mod test {
     fn create_option_usage_long_equals2() {
         let a = Arg::from("--opt=[option] 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -703,9 +720,9 @@ mod test {
     fn create_option_usage_long_equals3() {
         let a = Arg::from("<option> --opt=<opt> 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -719,9 +736,9 @@ mod test {
     fn create_option_usage_long_equals4() {
         let a = Arg::from("--opt=<option> 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -735,9 +752,9 @@ mod test {
     fn create_option_usage_long_equals5() {
         let a = Arg::from("[option] --opt=[opt]... 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -751,9 +768,9 @@ mod test {
     fn create_option_usage_long_equals6() {
         let a = Arg::from("[option]... --opt=[opt] 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::TakesValue));
         assert!(!a.is_set(ArgSettings::Required));
@@ -765,9 +782,9 @@ mod test {
     fn create_option_usage_long_equals7() {
         let a = Arg::from("--opt=[option]... 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -781,9 +798,9 @@ mod test {
     fn create_option_usage_long_equals8() {
         let a = Arg::from("<option> --opt=<opt>... 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -797,9 +814,9 @@ mod test {
     fn create_option_usage_long_equals9() {
         let a = Arg::from("<option>... --opt=<opt> 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::TakesValue));
         assert!(a.is_set(ArgSettings::Required));
@@ -811,9 +828,9 @@ mod test {
     fn create_option_usage_long_equals10() {
         let a = Arg::from("--opt=<option>... 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -827,9 +844,9 @@ mod test {
     fn create_option_usage_both1() {
         let a = Arg::from("[option] -o --opt [option] 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -843,9 +860,9 @@ mod test {
     fn create_option_usage_both2() {
         let a = Arg::from("-o --opt [option] 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -859,9 +876,9 @@ mod test {
     fn create_option_usage_both3() {
         let a = Arg::from("<option> -o --opt <opt> 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -875,9 +892,9 @@ mod test {
     fn create_option_usage_both4() {
         let a = Arg::from("-o --opt <option> 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -891,9 +908,9 @@ mod test {
     fn create_option_usage_both5() {
         let a = Arg::from("[option]... -o --opt [option] 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::TakesValue));
         assert!(!a.is_set(ArgSettings::Required));
@@ -905,9 +922,9 @@ mod test {
     fn create_option_usage_both6() {
         let a = Arg::from("-o --opt [option]... 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -921,9 +938,9 @@ mod test {
     fn create_option_usage_both7() {
         let a = Arg::from("<option>... -o --opt <opt> 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::TakesValue));
         assert!(a.is_set(ArgSettings::Required));
@@ -935,9 +952,9 @@ mod test {
     fn create_option_usage_both8() {
         let a = Arg::from("-o --opt <option>... 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -951,9 +968,9 @@ mod test {
     fn create_option_usage_both_equals1() {
         let a = Arg::from("[option] -o --opt=[option] 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -967,9 +984,9 @@ mod test {
     fn create_option_usage_both_equals2() {
         let a = Arg::from("-o --opt=[option] 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -983,9 +1000,9 @@ mod test {
     fn create_option_usage_both_equals3() {
         let a = Arg::from("<option> -o --opt=<opt> 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -999,9 +1016,9 @@ mod test {
     fn create_option_usage_both_equals4() {
         let a = Arg::from("-o --opt=<option> 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -1015,9 +1032,9 @@ mod test {
     fn create_option_usage_both_equals5() {
         let a = Arg::from("[option]... -o --opt=[option] 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::TakesValue));
         assert!(!a.is_set(ArgSettings::Required));
@@ -1029,9 +1046,9 @@ mod test {
     fn create_option_usage_both_equals6() {
         let a = Arg::from("-o --opt=[option]... 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -1045,9 +1062,9 @@ mod test {
     fn create_option_usage_both_equals7() {
         let a = Arg::from("<option>... -o --opt=<opt> 'some help info'");
         assert_eq!(a.name, "option");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(a.is_set(ArgSettings::MultipleOccurrences));
         assert!(a.is_set(ArgSettings::TakesValue));
         assert!(a.is_set(ArgSettings::Required));
@@ -1059,9 +1076,9 @@ mod test {
     fn create_option_usage_both_equals8() {
         let a = Arg::from("-o --opt=<option>... 'some help info'");
         assert_eq!(a.name, "opt");
-        assert_eq!(a.long.unwrap(), "opt");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -1077,7 +1094,7 @@ mod test {
         assert_eq!(a.name, "o");
         assert!(a.long.is_none());
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -1093,7 +1110,7 @@ mod test {
         assert_eq!(a.name, "o");
         assert!(a.long.is_none());
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -1108,8 +1125,8 @@ mod test {
         let a = Arg::from("--opt <file> <mode>... 'some help info'");
         assert_eq!(a.name, "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.long.unwrap(), "opt");
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -1124,8 +1141,8 @@ mod test {
         let a = Arg::from("[myopt] --opt <file> <mode> 'some help info'");
         assert_eq!(a.name, "myopt");
         assert!(a.short.is_none());
-        assert_eq!(a.long.unwrap(), "opt");
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -1140,8 +1157,8 @@ mod test {
         let a = Arg::from("--opt <file> <mode> 'some help info'");
         assert_eq!(a.name, "opt");
         assert!(a.short.is_none());
-        assert_eq!(a.long.unwrap(), "opt");
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -1154,7 +1171,7 @@ mod test {
     fn create_positional_usage() {
         let a = Arg::from("[pos] 'some help info'");
         assert_eq!(a.name, "pos");
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -1167,7 +1184,7 @@ mod test {
     fn create_positional_usage0() {
         let a = Arg::from("<pos> 'some help info'");
         assert_eq!(a.name, "pos");
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -1180,7 +1197,7 @@ mod test {
     fn pos_mult_help() {
         let a = Arg::from("[pos]... 'some help info'");
         assert_eq!(a.name, "pos");
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -1193,7 +1210,7 @@ mod test {
     fn pos_help_lit_single_quote() {
         let a = Arg::from("[pos]... 'some help\' info'");
         assert_eq!(a.name, "pos");
-        assert_eq!(a.about.unwrap(), "some help' info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help' info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -1206,7 +1223,7 @@ mod test {
     fn pos_help_double_lit_single_quote() {
         let a = Arg::from("[pos]... 'some \'help\' info'");
         assert_eq!(a.name, "pos");
-        assert_eq!(a.about.unwrap(), "some 'help' info");
+        assert_eq!(a.about.as_deref().unwrap(), "some 'help' info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -1222,7 +1239,7 @@ mod test {
              info'",
         );
         assert_eq!(a.name, "pos");
-        assert_eq!(a.about.unwrap(), "some help\ninfo");
+        assert_eq!(a.about.as_deref().unwrap(), "some help\ninfo");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -1238,7 +1255,7 @@ mod test {
              info'",
         );
         assert_eq!(a.name, "pos");
-        assert_eq!(a.about.unwrap(), "some help' stuff\ninfo");
+        assert_eq!(a.about.as_deref().unwrap(), "some help' stuff\ninfo");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -1251,7 +1268,7 @@ mod test {
     fn pos_req_mult_help() {
         let a = Arg::from("<pos>... 'some help info'");
         assert_eq!(a.name, "pos");
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -1288,7 +1305,7 @@ mod test {
     fn pos_req_mult_def_help() {
         let a = Arg::from("<pos>... @a 'some help info'");
         assert_eq!(a.name, "pos");
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             a.is_set(ArgSettings::MultipleValues) && a.is_set(ArgSettings::MultipleOccurrences)
         );
@@ -1304,7 +1321,7 @@ mod test {
         assert_eq!(a.name, "o");
         assert!(a.long.is_none());
         assert_eq!(a.short.unwrap(), 'o');
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -1320,8 +1337,8 @@ mod test {
         let a = Arg::from("[myopt] --opt <file> <mode> @a 'some help info'");
         assert_eq!(a.name, "myopt");
         assert!(a.short.is_none());
-        assert_eq!(a.long.unwrap(), "opt");
-        assert_eq!(a.about.unwrap(), "some help info");
+        assert_eq!(a.long.as_deref().unwrap(), "opt");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
         assert!(
             !(a.is_set(ArgSettings::MultipleValues) || a.is_set(ArgSettings::MultipleOccurrences))
         );
@@ -1336,25 +1353,53 @@ mod test {
     fn nonascii() {
         let a = Arg::from("<ASCII> 'üñíčöĐ€'");
         assert_eq!(a.name, "ASCII");
-        assert_eq!(a.about, Some("üñíčöĐ€"));
+        assert_eq!(a.about.as_deref(), Some("üñíčöĐ€"));
         let a = Arg::from("<üñíčöĐ€> 'ASCII'");
         assert_eq!(a.name, "üñíčöĐ€");
-        assert_eq!(a.about, Some("ASCII"));
+        assert_eq!(a.about.as_deref(), Some("ASCII"));
         let a = Arg::from("<üñíčöĐ€> 'üñíčöĐ€'");
         assert_eq!(a.name, "üñíčöĐ€");
-        assert_eq!(a.about, Some("üñíčöĐ€"));
+        assert_eq!(a.about.as_deref(), Some("üñíčöĐ€"));
         let a = Arg::from("-ø 'ø'");
         assert_eq!(a.name, "ø");
         assert_eq!(a.short, Some('ø'));
-        assert_eq!(a.about, Some("ø"));
+        assert_eq!(a.about.as_deref(), Some("ø"));
         let a = Arg::from("--üñíčöĐ€ 'Nōṫ ASCII'");
         assert_eq!(a.name, "üñíčöĐ€");
-        assert_eq!(a.long, Some("üñíčöĐ€"));
-        assert_eq!(a.about, Some("Nōṫ ASCII"));
+        assert_eq!(a.long.as_deref(), Some("üñíčöĐ€"));
+        assert_eq!(a.about.as_deref(), Some("Nōṫ ASCII"));
         let a = Arg::from("[ñämê] --ôpt=[üñíčöĐ€] 'hælp'");
         assert_eq!(a.name, "ñämê");
-        assert_eq!(a.long, Some("ôpt"));
+        assert_eq!(a.long.as_deref(), Some("ôpt"));
         assert_eq!(a.val_names.values().collect::<Vec<_>>(), [&"üñíčöĐ€"]);
-        assert_eq!(a.about, Some("hælp"));
+        assert_eq!(a.about.as_deref(), Some("hælp"));
+    }
+
+    #[test]
+    fn create_option_usage_delimiter() {
+        let a = Arg::from("--list=[ITEM]{,} 'some help info'");
+        assert_eq!(a.name, "list");
+        assert_eq!(a.long.as_deref().unwrap(), "list");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
+        assert!(a.is_set(ArgSettings::TakesValue));
+        assert!(a.is_set(ArgSettings::UseValueDelimiter));
+        assert_eq!(a.val_delim, Some(","));
+        assert!(!a.is_set(ArgSettings::MultipleOccurrences));
+        assert!(!a.is_set(ArgSettings::MultipleValues));
+        assert_eq!(a.val_names.values().collect::<Vec<_>>(), [&"ITEM"]);
+    }
+
+    #[test]
+    fn create_option_usage_delimiter_and_multiple() {
+        let a = Arg::from("--list=[ITEM]{;}... 'some help info'");
+        assert_eq!(a.name, "list");
+        assert_eq!(a.long.as_deref().unwrap(), "list");
+        assert_eq!(a.about.as_deref().unwrap(), "some help info");
+        assert!(a.is_set(ArgSettings::TakesValue));
+        assert!(a.is_set(ArgSettings::UseValueDelimiter));
+        assert_eq!(a.val_delim, Some(";"));
+        assert!(a.is_set(ArgSettings::MultipleOccurrences));
+        assert!(a.is_set(ArgSettings::MultipleValues));
+        assert_eq!(a.val_names.values().collect::<Vec<_>>(), [&"ITEM"]);
     }
 }
@@ -24,9 +24,9 @@
 compile_error!("`std` feature is currently required to build `clap`");
 
 pub use crate::{
-    build::{App, AppSettings, Arg, ArgGroup, ArgSettings, ValueHint},
+    build::{App, AppSettings, Arg, ArgGroup, ArgSettings, DefaultWhen, ValueHint},
     parse::errors::{Error, ErrorKind, Result},
-    parse::{ArgMatches, Indices, OsValues, Values},
+    parse::{ArgMatches, Indices, OsValues, ValueSource, Values},
 };
 
 #[cfg(feature = "derive")]
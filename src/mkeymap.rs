@@ -1,4 +1,4 @@
-use crate::{build::Arg, util::Id, INTERNAL_ERROR_MSG};
+use crate::{build::Arg, INTERNAL_ERROR_MSG};
 
 use std::{ffi::OsString, iter::Iterator, ops::Index};
 
@@ -126,12 +126,12 @@ impl<'help> MKeyMap<'help> {
         }
     }
 
-    /// Remove an arg in the graph by Id, usually used by `mut_arg`. Return
+    /// Remove an arg in the graph by name, usually used by `mut_arg`. Return
     /// `Some(arg)` if removed.
-    pub(crate) fn remove_by_name(&mut self, name: &Id) -> Option<Arg<'help>> {
+    pub(crate) fn remove_by_name(&mut self, name: &str) -> Option<Arg<'help>> {
         self.args
             .iter()
-            .position(|arg| &arg.id == name)
+            .position(|arg| arg.name == name)
             // since it's a cold function, using this wouldn't hurt much
             .map(|i| self.args.remove(i))
     }
@@ -161,7 +161,7 @@ fn _get_keys(arg: &Arg) -> Vec<KeyType> {
     if let Some(short) = arg.short {
         keys.push(KeyType::Short(short));
     }
-    if let Some(long) = arg.long {
+    if let Some(long) = arg.long.as_deref() {
         keys.push(KeyType::Long(OsString::from(long)));
     }
 
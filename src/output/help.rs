@@ -100,6 +100,7 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
     pub(crate) fn write_help(&mut self) -> io::Result<()> {
         debug!("Help::write_help");
 
+        let show_hidden = self.parser.app.is_set(AppSettings::ShowHiddenArgs);
         if let Some(h) = self.parser.app.help_str {
             self.none(h)?;
         } else if let Some(tmpl) = self.parser.app.template {
@@ -109,17 +110,17 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
                 .parser
                 .app
                 .get_positionals()
-                .any(|arg| should_show_arg(self.use_long, arg));
+                .any(|arg| should_show_arg(self.use_long, arg, show_hidden));
             let flags = self
                 .parser
                 .app
                 .get_flags()
-                .any(|arg| should_show_arg(self.use_long, arg));
+                .any(|arg| should_show_arg(self.use_long, arg, show_hidden));
             let opts = self
                 .parser
                 .app
                 .get_opts()
-                .any(|arg| should_show_arg(self.use_long, arg));
+                .any(|arg| should_show_arg(self.use_long, arg, show_hidden));
             let subcmds = self.parser.app.has_visible_subcommands();
 
             if flags || opts || pos || subcmds {
@@ -175,16 +176,17 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
     /// Writes help for each argument in the order they were declared to the wrapped stream.
     fn write_args_unsorted(&mut self, args: &[&Arg<'help>]) -> io::Result<()> {
         debug!("Help::write_args_unsorted");
+        let show_hidden = self.parser.app.is_set(AppSettings::ShowHiddenArgs);
         // The shortest an arg can legally be is 2 (i.e. '-x')
         let mut longest = 2;
         let mut arg_v = Vec::with_capacity(10);
 
         for arg in args
             .iter()
-            .filter(|arg| should_show_arg(self.use_long, *arg))
+            .filter(|arg| should_show_arg(self.use_long, arg, show_hidden))
         {
             if arg.longest_filter() {
-                longest = longest.max(display_width(arg.to_string().as_str()));
+                longest = longest.max(display_width(arg.to_string().as_str()) + unit_width(arg));
             }
             arg_v.push(arg)
         }
@@ -201,6 +203,7 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
     /// Sorts arguments by length and display order and write their help to the wrapped stream.
     fn write_args(&mut self, args: &[&Arg<'help>]) -> io::Result<()> {
         debug!("Help::write_args");
+        let show_hidden = self.parser.app.is_set(AppSettings::ShowHiddenArgs);
         // The shortest an arg can legally be is 2 (i.e. '-x')
         let mut longest = 2;
         let mut ord_m = VecMap::new();
@@ -210,11 +213,11 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
             // If it's NextLineHelp we don't care to compute how long it is because it may be
             // NextLineHelp on purpose simply *because* it's so long and would throw off all other
             // args alignment
-            should_show_arg(self.use_long, *arg)
+            should_show_arg(self.use_long, arg, show_hidden)
         }) {
             if arg.longest_filter() {
                 debug!("Help::write_args: Current Longest...{}", longest);
-                longest = longest.max(display_width(arg.to_string().as_str()));
+                longest = longest.max(display_width(arg.to_string().as_str()) + unit_width(arg));
                 debug!("Help::write_args: New Longest...{}", longest);
             }
             let btm = ord_m.entry(arg.disp_ord).or_insert(BTreeMap::new());
@@ -231,7 +234,7 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
                 let mut s = x.to_ascii_lowercase().to_string();
                 s.push(if x.is_ascii_lowercase() { '0' } else { '1' });
                 s
-            } else if let Some(x) = arg.long {
+            } else if let Some(x) = arg.long.as_deref() {
                 x.to_string()
             } else {
                 let mut s = '{'.to_string();
@@ -298,20 +301,22 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
             return Ok(());
         }
         if arg.is_set(ArgSettings::TakesValue) {
-            if let Some(l) = arg.long {
+            if let Some(l) = arg.long.as_deref() {
                 if arg.short.is_some() {
                     self.none(", ")?;
                 }
                 self.good(&format!("--{}", l))?
             }
 
-            let sep = if arg.is_set(ArgSettings::RequireEquals) {
+            let sep = if arg.is_set(ArgSettings::ValueOptional) {
+                "[="
+            } else if arg.is_set(ArgSettings::RequireEquals) {
                 "="
             } else {
                 " "
             };
             self.none(sep)?;
-        } else if let Some(l) = arg.long {
+        } else if let Some(l) = arg.long.as_deref() {
             if arg.short.is_some() {
                 self.none(", ")?;
             }
@@ -329,39 +334,45 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
             let delim = if arg.is_set(ArgSettings::RequireDelimiter) {
                 arg.val_delim.expect(INTERNAL_ERROR_MSG)
             } else {
-                ' '
+                " "
             };
             if !arg.val_names.is_empty() {
                 let mut it = arg.val_names.iter().peekable();
                 while let Some((_, val)) = it.next() {
-                    self.good(&format!("<{}>", val))?;
+                    self.good(format!("<{}>", arg.render_val_name(val)))?;
                     if it.peek().is_some() {
-                        self.none(&delim.to_string())?;
+                        self.none(delim)?;
                     }
                 }
                 let num = arg.val_names.len();
-                if mult && num == 1 {
+                if mult && (num == 1 || arg.val_labels) {
                     self.good("...")?;
                 }
             } else if let Some(num) = arg.num_vals {
                 let mut it = (0..num).peekable();
                 while let Some(_) = it.next() {
-                    self.good(&format!("<{}>", arg.name))?;
+                    self.good(format!("<{}>", arg.render_val_name(arg.name)))?;
                     if it.peek().is_some() {
-                        self.none(&delim.to_string())?;
+                        self.none(delim)?;
                     }
                 }
                 if mult && num == 1 {
                     self.good("...")?;
                 }
             } else if arg.has_switch() {
-                self.good(&format!("<{}>", arg.name))?;
+                self.good(format!("<{}>", arg.render_val_name(arg.name)))?;
                 if mult {
                     self.good("...")?;
                 }
             } else {
                 self.good(&arg.to_string())?;
             }
+            if arg.is_set(ArgSettings::ValueOptional) && arg.has_switch() {
+                self.none("]")?;
+            }
+            if let Some(unit) = arg.value_unit {
+                self.none(format!(" ({})", unit))?;
+            }
         }
 
         debug!("Help::val: Has switch...");
@@ -372,7 +383,7 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
             debug!("Yes");
             debug!("Help::val: nlh...{:?}", next_line_help);
             if !next_line_help {
-                let self_len = display_width(arg.to_string().as_str());
+                let self_len = display_width(arg.to_string().as_str()) + unit_width(arg);
                 // subtract ourself
                 let mut spcs = longest - self_len;
                 // Since we're writing spaces from the tab point we first need to know if we
@@ -389,7 +400,7 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
             }
         } else if !next_line_help {
             debug!("No, and not next_line");
-            self.spaces(longest + 4 - display_width(&arg.to_string()))?;
+            self.spaces(longest + 4 - display_width(&arg.to_string()) - unit_width(arg))?;
         } else {
             debug!("No");
         }
@@ -498,19 +509,40 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
         self.val(arg, next_line_help, longest)?;
 
         let about = if self.use_long {
-            arg.long_about.unwrap_or_else(|| arg.about.unwrap_or(""))
+            arg.get_long_about_or_about().unwrap_or("")
+        } else {
+            arg.get_about_or_long_about().unwrap_or("")
+        };
+        let about = if arg.interpolate_help {
+            Cow::Owned(self.interpolate_bin(about))
         } else {
-            arg.about.unwrap_or_else(|| arg.long_about.unwrap_or(""))
+            Cow::Borrowed(about)
         };
 
-        self.help(arg.has_switch(), about, spec_vals, next_line_help, longest)?;
+        self.help(arg.has_switch(), &about, spec_vals, next_line_help, longest)?;
         Ok(())
     }
 
+    /// Expands the `{bin}` token in help text to the binary name. `{{bin}}` escapes to a
+    /// literal `{bin}`.
+    fn interpolate_bin(&self, text: &str) -> String {
+        let bin = self
+            .parser
+            .app
+            .bin_name
+            .as_deref()
+            .unwrap_or(&self.parser.app.name);
+        text.split("{{bin}}")
+            .map(|part| part.replace("{bin}", bin))
+            .collect::<Vec<_>>()
+            .join("{bin}")
+    }
+
     /// Will use next line help on writing args.
     fn will_args_wrap(&self, args: &[&Arg<'help>], longest: usize) -> bool {
+        let show_hidden = self.parser.app.is_set(AppSettings::ShowHiddenArgs);
         args.iter()
-            .filter(|arg| should_show_arg(self.use_long, *arg))
+            .filter(|arg| should_show_arg(self.use_long, arg, show_hidden))
             .any(|arg| {
                 let spec_vals = &self.spec_vals(arg);
                 self.arg_next_line_help(arg, spec_vals, longest)
@@ -523,7 +555,7 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
             true
         } else {
             // force_next_line
-            let h = arg.about.unwrap_or("");
+            let h = arg.about.as_deref().unwrap_or("");
             let h_w = display_width(h) + display_width(spec_vals);
             let taken = longest + 12;
             self.term_w >= taken
@@ -555,7 +587,16 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
                 spec_vals.push(env_info);
             }
         }
-        if !a.is_set(ArgSettings::HideDefaultValue) && !a.default_vals.is_empty() {
+        // A conditional hide shows the default only when the referenced arg exists and is itself
+        // visible in this help message.
+        let show_default_if = a.hide_default_if.as_ref().is_none_or(|id| {
+            self.parser
+                .app
+                .find(id)
+                .is_some_and(|target| !target.is_set(ArgSettings::Hidden))
+        });
+        if !a.is_set(ArgSettings::HideDefaultValue) && show_default_if && !a.default_vals.is_empty()
+        {
             debug!(
                 "Help::spec_vals: Found default value...[{:?}]",
                 a.default_vals
@@ -625,11 +666,24 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
                 .possible_vals
                 .iter()
                 .map(|&pv| {
-                    if pv.contains(char::is_whitespace) {
+                    let mut rendered = if pv.contains(char::is_whitespace) {
                         format!("{:?}", pv)
                     } else {
                         pv.to_string()
+                    };
+                    if a.show_pv_aliases {
+                        let als = a
+                            .possible_val_aliases
+                            .iter()
+                            .filter(|&&(_, canonical)| canonical == pv)
+                            .map(|&(als, _)| als)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        if !als.is_empty() {
+                            rendered = format!("{} (aliases: {})", rendered, als);
+                        }
                     }
+                    rendered
                 })
                 .collect::<Vec<_>>()
                 .join(", ");
@@ -772,33 +826,48 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
     /// including titles of a Parser Object to the wrapped stream.
     pub(crate) fn write_all_args(&mut self) -> io::Result<()> {
         debug!("Help::write_all_args");
+        let show_hidden = self.parser.app.is_set(AppSettings::ShowHiddenArgs);
         let pos = self
             .parser
             .app
             .get_positionals_with_no_heading()
-            .filter(|arg| should_show_arg(self.use_long, arg))
+            .filter(|arg| should_show_arg(self.use_long, arg, show_hidden))
             .collect::<Vec<_>>();
         let flags = self
             .parser
             .app
             .get_flags_with_no_heading()
-            .filter(|arg| should_show_arg(self.use_long, arg))
+            .filter(|arg| should_show_arg(self.use_long, arg, show_hidden))
             .collect::<Vec<_>>();
         let opts = self
             .parser
             .app
             .get_opts_with_no_heading()
-            .filter(|arg| should_show_arg(self.use_long, arg))
+            .filter(|arg| should_show_arg(self.use_long, arg, show_hidden))
             .collect::<Vec<_>>();
         let subcmds = self.parser.app.has_visible_subcommands();
 
-        let custom_headings = self
+        let mut custom_headings = self
             .parser
             .app
             .args
             .args()
-            .filter_map(|arg| arg.help_heading)
-            .collect::<IndexSet<_>>();
+            .filter_map(|arg| arg.get_help_heading_normalized())
+            .collect::<IndexSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        // Each heading sorts by the minimum `Arg::help_heading_order` among its members;
+        // headings without any ordered member stay in definition order at the end.
+        custom_headings.sort_by_key(|heading| {
+            self.parser
+                .app
+                .args
+                .args()
+                .filter(|a| a.get_help_heading_normalized().as_ref() == Some(heading))
+                .filter_map(|a| a.help_heading_order)
+                .min()
+                .unwrap_or(usize::MAX)
+        });
 
         let mut first = if !pos.is_empty() {
             // Write positional args if any
@@ -854,7 +923,7 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
                         .args
                         .args()
                         .filter(|a| {
-                            if let Some(help_heading) = a.help_heading {
+                            if let Some(help_heading) = a.get_help_heading_normalized() {
                                 return help_heading == heading;
                             }
                             false
@@ -1073,8 +1142,12 @@ impl<'help, 'app, 'parser, 'writer> Help<'help, 'app, 'parser, 'writer> {
     }
 }
 
-fn should_show_arg(use_long: bool, arg: &Arg) -> bool {
+fn should_show_arg(use_long: bool, arg: &Arg, show_hidden: bool) -> bool {
     debug!("should_show_arg: use_long={:?}, arg={}", use_long, arg.name);
+    if arg.hide_unless_long_help {
+        // only the long help of an app with ShowHiddenArgs active shows these
+        return use_long && show_hidden;
+    }
     if arg.is_set(ArgSettings::Hidden) {
         return false;
     }
@@ -1087,6 +1160,12 @@ fn should_show_subcommand(subcommand: &App) -> bool {
     !subcommand.is_set(AppSettings::Hidden)
 }
 
+// Width of the ` (unit)` suffix rendered after the value name, if any; the suffix is not part
+// of `Arg`'s `Display` output so it needs accounting for separately when aligning
+fn unit_width(arg: &Arg) -> usize {
+    arg.value_unit.map_or(0, |u| display_width(u) + 3)
+}
+
 fn text_wrapper(help: &str, width: usize) -> String {
     let wrapper = textwrap::Options::new(width).break_words(false);
     help.lines()
@@ -223,7 +223,7 @@ impl<'help, 'app, 'parser> Usage<'help, 'app, 'parser> {
             .filter(|pos| !pos.is_set(ArgSettings::Last))
         {
             debug!("Usage::get_args_tag:iter:{}", pos.name);
-            let required = self.p.app.groups_for_arg(&pos.id).any(|grp_s| {
+            let required = self.p.app.groups_for_arg_id(&pos.id).any(|grp_s| {
                 debug!("Usage::get_args_tag:iter:{:?}:iter:{:?}", pos.name, grp_s);
                 // if it's part of a required group we don't want to count it
                 self.p
@@ -255,7 +255,7 @@ impl<'help, 'app, 'parser> Usage<'help, 'app, 'parser> {
                     !pos.is_set(ArgSettings::Required)
                         && !pos.is_set(ArgSettings::Hidden)
                         && !pos.is_set(ArgSettings::Last)
-                        && !self.p.app.groups_for_arg(&pos.id).any(|grp_s| {
+                        && !self.p.app.groups_for_arg_id(&pos.id).any(|grp_s| {
                             debug!("Usage::get_args_tag:iter:{:?}:iter:{:?}", pos.name, grp_s);
                             // if it's part of a required group we don't want to count it
                             self.p
@@ -332,14 +332,14 @@ impl<'help, 'app, 'parser> Usage<'help, 'app, 'parser> {
             debug!("Usage::needs_flags_tag:iter: f={}", f.name);
 
             // Don't print `[FLAGS]` just for help or version
-            if f.long == Some("help") || f.long == Some("version") {
+            if f.long.as_deref() == Some("help") || f.long.as_deref() == Some("version") {
                 continue;
             }
 
             if f.is_set(ArgSettings::Hidden) {
                 continue;
             }
-            for grp_s in self.p.app.groups_for_arg(&f.id) {
+            for grp_s in self.p.app.groups_for_arg_id(&f.id) {
                 debug!("Usage::needs_flags_tag:iter:iter: grp_s={:?}", grp_s);
                 if self
                     .p
@@ -165,6 +165,24 @@ pub enum ErrorKind {
     /// [`Arg::max_values`]: ./struct.Arg.html#method.max_values
     TooManyValues,
 
+    /// Occurs when a user provides an argument more times than allowed by setting
+    /// [`Arg::count_with_max`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let result = App::new("prog")
+    ///     .arg(Arg::new("verbose")
+    ///         .short('v')
+    ///         .count_with_max(3))
+    ///     .try_get_matches_from(vec!["prog", "-vvvv"]);
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().kind, ErrorKind::TooManyOccurrences);
+    /// ```
+    /// [`Arg::count_with_max`]: ./struct.Arg.html#method.count_with_max
+    TooManyOccurrences,
+
     /// Occurs when the user provides fewer values for an argument than were defined by setting
     /// [`Arg::min_values`].
     ///
@@ -390,6 +408,12 @@ pub struct Error {
     /// Useful when you want to render an error of your own.
     pub info: Vec<String>,
     pub(crate) source: Option<Box<dyn error::Error + Send + Sync>>,
+    /// The exit code [`Error::exit`] uses instead of the default `1`, when set via
+    /// [`Arg::validation_exit_code`].
+    ///
+    /// [`Error::exit`]: ./struct.Error.html#method.exit
+    /// [`Arg::validation_exit_code`]: ./struct.Arg.html#method.validation_exit_code
+    pub exit_code: Option<i32>,
 }
 
 impl Display for Error {
@@ -438,11 +462,14 @@ impl Error {
 
     /// Prints the error and exits. Depending on the error kind, this
     /// either prints to `stderr` and exits with a status of `1`
+    /// (or the [`exit_code`] carried by the error, if any)
     /// or prints to `stdout` and exits with a status of `0`.
+    ///
+    /// [`exit_code`]: ./struct.Error.html#structfield.exit_code
     pub fn exit(&self) -> ! {
         if self.use_stderr() {
             self.message.print().expect("Error writing Error to stderr");
-            safe_exit(1);
+            safe_exit(self.exit_code.unwrap_or(1));
         }
 
         self.message.print().expect("Error writing Error to stdout");
@@ -506,6 +533,7 @@ impl Error {
             kind: ErrorKind::ArgumentConflict,
             info,
             source: None,
+            exit_code: None,
         }
     }
 
@@ -524,6 +552,7 @@ impl Error {
             kind: ErrorKind::EmptyValue,
             info: vec![arg],
             source: None,
+            exit_code: None,
         }
     }
 
@@ -587,6 +616,7 @@ impl Error {
             kind: ErrorKind::InvalidValue,
             info: vec![],
             source: None,
+            exit_code: None,
         }
     }
 
@@ -618,6 +648,7 @@ impl Error {
             kind: ErrorKind::InvalidSubcommand,
             info: vec![subcmd],
             source: None,
+            exit_code: None,
         }
     }
 
@@ -640,6 +671,7 @@ impl Error {
             kind: ErrorKind::UnrecognizedSubcommand,
             info: vec![subcmd],
             source: None,
+            exit_code: None,
         }
     }
 
@@ -670,6 +702,7 @@ impl Error {
             kind: ErrorKind::MissingRequiredArgument,
             info,
             source: None,
+            exit_code: None,
         }
     }
 
@@ -687,6 +720,7 @@ impl Error {
             kind: ErrorKind::MissingSubcommand,
             info: vec![],
             source: None,
+            exit_code: None,
         }
     }
 
@@ -705,6 +739,7 @@ impl Error {
             kind: ErrorKind::InvalidUtf8,
             info: vec![],
             source: None,
+            exit_code: None,
         }
     }
 
@@ -729,6 +764,7 @@ impl Error {
             kind: ErrorKind::TooManyValues,
             info: vec![arg.to_string(), val],
             source: None,
+            exit_code: None,
         }
     }
 
@@ -757,6 +793,7 @@ impl Error {
             kind: ErrorKind::TooFewValues,
             info: vec![arg.to_string(), curr_vals.to_string(), min_vals.to_string()],
             source: None,
+            exit_code: None,
         }
     }
 
@@ -782,6 +819,67 @@ impl Error {
             kind: ErrorKind::ValueValidation,
             info: vec![arg, val, err.to_string()],
             source: Some(err),
+            exit_code: None,
+        }
+    }
+
+    pub(crate) fn value_validation_with_suggestions(
+        arg: String,
+        val: String,
+        msg: String,
+        suggestions: &[String],
+        color: ColorChoice,
+    ) -> Self {
+        let mut c = Colorizer::new(true, color);
+
+        start_error(&mut c, "Invalid value");
+
+        c.none(" for '");
+        c.warning(arg.clone());
+        c.none("'");
+
+        c.none(format!(": {}", msg));
+
+        if let Some(candidate) = suggestions::did_you_mean(&val, suggestions.iter()).pop() {
+            c.none("\n\n\tDid you mean '");
+            c.good(candidate);
+            c.none("'?");
+        }
+        try_help(&mut c);
+
+        Error {
+            message: c,
+            kind: ErrorKind::ValueValidation,
+            info: vec![arg, val, msg],
+            source: None,
+            exit_code: None,
+        }
+    }
+
+    pub(crate) fn aggregated_value_validation(errors: Vec<Error>, color: ColorChoice) -> Self {
+        debug_assert!(errors.len() > 1);
+        let mut c = Colorizer::new(true, color);
+
+        start_error(&mut c, "Found ");
+        c.warning(errors.len().to_string());
+        c.none(" invalid values:");
+
+        let mut info = vec![];
+        for e in errors {
+            // `value_validation` stores `[arg, val, err]` in `info`
+            c.none("\n    Invalid value for '");
+            c.warning(e.info[0].clone());
+            c.none(format!("': {}", e.info[2]));
+            info.extend(e.info);
+        }
+        try_help(&mut c);
+
+        Error {
+            message: c,
+            kind: ErrorKind::ValueValidation,
+            info,
+            source: None,
+            exit_code: None,
         }
     }
 
@@ -810,6 +908,39 @@ impl Error {
             kind: ErrorKind::WrongNumberOfValues,
             info: vec![arg.to_string(), curr_vals.to_string(), num_vals.to_string()],
             source: None,
+            exit_code: None,
+        }
+    }
+
+    pub(crate) fn too_many_occurrences(
+        arg: &Arg,
+        max_occurs: u64,
+        curr_occurs: u64,
+        usage: String,
+        color: ColorChoice,
+    ) -> Self {
+        let mut c = Colorizer::new(true, color);
+
+        start_error(&mut c, "The argument '");
+        c.warning(arg.to_string());
+        c.none("' was provided ");
+        c.warning(curr_occurs.to_string());
+        c.none(" times, but may be used at most ");
+        c.warning(max_occurs.to_string());
+        c.none(" times");
+        put_usage(&mut c, usage);
+        try_help(&mut c);
+
+        Error {
+            message: c,
+            kind: ErrorKind::TooManyOccurrences,
+            info: vec![
+                arg.to_string(),
+                curr_occurs.to_string(),
+                max_occurs.to_string(),
+            ],
+            source: None,
+            exit_code: None,
         }
     }
 
@@ -828,6 +959,7 @@ impl Error {
             kind: ErrorKind::UnexpectedMultipleUsage,
             info: vec![arg],
             source: None,
+            exit_code: None,
         }
     }
 
@@ -877,6 +1009,7 @@ impl Error {
             kind: ErrorKind::UnknownArgument,
             info: vec![arg],
             source: None,
+            exit_code: None,
         }
     }
 
@@ -899,6 +1032,7 @@ impl Error {
             kind: ErrorKind::UnknownArgument,
             info: vec![arg],
             source: None,
+            exit_code: None,
         }
     }
 
@@ -915,6 +1049,7 @@ impl Error {
             kind: ErrorKind::ArgumentNotFound,
             info: vec![arg],
             source: None,
+            exit_code: None,
         }
     }
 
@@ -932,6 +1067,7 @@ impl Error {
             kind,
             info: vec![],
             source: None,
+            exit_code: None,
         }
     }
 }
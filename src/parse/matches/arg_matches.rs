@@ -13,11 +13,28 @@ use indexmap::IndexMap;
 
 // Internal
 use crate::{
-    parse::MatchedArg,
+    parse::{MatchedArg, ValueType},
     util::{termcolor::ColorChoice, Id, Key},
     {Error, INVALID_UTF8},
 };
 
+/// The origin of an argument's value at runtime, reported by [`ArgMatches::value_source`].
+///
+/// [`ArgMatches::value_source`]: ./struct.ArgMatches.html#method.value_source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueSource {
+    /// The value was supplied on the command line
+    CommandLine,
+    /// The value was read from an environment variable set via [`Arg::env`]
+    ///
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    EnvVariable,
+    /// The value came from [`Arg::default_value`] or one of its conditional variants
+    ///
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    DefaultValue,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct SubCommand {
     pub(crate) id: Id,
@@ -102,7 +119,9 @@ impl ArgMatches {
     ///
     /// # Panics
     ///
-    /// This method will [`panic!`] if the value contains invalid UTF-8 code points.
+    /// This method will [`panic!`] if the value contains invalid UTF-8 code points, unless the
+    /// argument was built with [`Arg::allow_invalid_utf8`], in which case `None` is returned
+    /// and [`ArgMatches::value_of_os`] is the correct accessor.
     ///
     /// # Examples
     ///
@@ -115,6 +134,8 @@ impl ArgMatches {
     ///
     /// assert_eq!(m.value_of("output"), Some("something"));
     /// ```
+    /// [`Arg::allow_invalid_utf8`]: ./struct.Arg.html#method.allow_invalid_utf8
+    /// [`ArgMatches::value_of_os`]: ./struct.ArgMatches.html#method.value_of_os
     /// [option]: ./struct.Arg.html#method.takes_value
     /// [positional]: ./struct.Arg.html#method.index
     /// [`ArgMatches::values_of`]: ./struct.ArgMatches.html#method.values_of
@@ -124,12 +145,46 @@ impl ArgMatches {
     pub fn value_of<T: Key>(&self, id: T) -> Option<&str> {
         if let Some(arg) = self.args.get(&Id::from(id)) {
             if let Some(v) = arg.get_val(0) {
+                if arg.allow_invalid_utf8 {
+                    return v.to_str();
+                }
                 return Some(v.to_str().expect(INVALID_UTF8));
             }
         }
         None
     }
 
+    /// Gets the first value of a specific argument split into its `(key, value)` halves on the
+    /// delimiter configured via [`Arg::key_value_delimiter`]. If the argument wasn't present at
+    /// runtime it returns `None`. Values are guaranteed to contain the delimiter; parsing
+    /// rejects those that don't.
+    ///
+    /// # Panics
+    ///
+    /// This method will [`panic!`] if the value contains invalid UTF-8 code points.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::new("env")
+    ///         .long("env")
+    ///         .key_value_delimiter('='))
+    ///     .get_matches_from(vec!["myapp", "--env", "FOO=bar"]);
+    ///
+    /// assert_eq!(m.key_value_of("env"), Some(("FOO", "bar")));
+    /// ```
+    /// [`Arg::key_value_delimiter`]: ./struct.Arg.html#method.key_value_delimiter
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    pub fn key_value_of<T: Key>(&self, id: T) -> Option<(&str, &str)> {
+        let arg = self.args.get(&Id::from(id))?;
+        let delim = arg.key_val_delim.unwrap_or('=');
+        let val = arg.get_val(0)?.to_str().expect(INVALID_UTF8);
+        let i = val.find(delim)?;
+        Some((&val[..i], &val[i + delim.len_utf8()..]))
+    }
+
     /// Gets the lossy value of a specific argument. If the argument wasn't present at runtime
     /// it returns `None`. A lossy value is one which contains invalid UTF-8 code points, those
     /// invalid points will be replaced with `\u{FFFD}`
@@ -242,7 +297,35 @@ impl ArgMatches {
         })
     }
 
-    /// Placeholder documentation.
+    /// Gets the values of a specific argument grouped by occurrence, one inner `Vec` per
+    /// occurrence, where [`ArgMatches::values_of`] would flatten everything into a single list.
+    /// Combined with [`Arg::number_of_values`] and [`ArgSettings::MultipleOccurrences`] this
+    /// supports repeatable tuples like `--point X Y --point X Y`.
+    ///
+    /// # Panics
+    ///
+    /// This method will [`panic!`] if any of the values contain invalid UTF-8 code points.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::new("point")
+    ///         .long("point")
+    ///         .number_of_values(2)
+    ///         .multiple_occurrences(true))
+    ///     .get_matches_from(vec![
+    ///         "myapp", "--point", "1", "2", "--point", "3", "4"
+    ///     ]);
+    ///
+    /// let points: Vec<Vec<&str>> = m.grouped_values_of("point").unwrap().collect();
+    /// assert_eq!(points, vec![vec!["1", "2"], vec!["3", "4"]]);
+    /// ```
+    /// [`ArgMatches::values_of`]: ./struct.ArgMatches.html#method.values_of
+    /// [`Arg::number_of_values`]: ./struct.Arg.html#method.number_of_values
+    /// [`ArgSettings::MultipleOccurrences`]: ./enum.ArgSettings.html#variant.MultipleOccurrences
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
     pub fn grouped_values_of<T: Key>(&self, id: T) -> Option<GroupedValues> {
         #[allow(clippy::type_complexity)]
         let arg_values: for<'a> fn(
@@ -599,6 +682,66 @@ impl ArgMatches {
         self.args.get(&Id::from(id)).map_or(0, |a| a.occurs)
     }
 
+    /// Returns the occurrence count of an argument as its value, for use with counted flags
+    /// like [`Arg::count_with_max`]. If an argument isn't present it will return `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::new("verbose")
+    ///         .short('v')
+    ///         .count_with_max(3))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "-vvv"
+    ///     ]);
+    ///
+    /// assert_eq!(m.count_of("verbose"), 3);
+    /// ```
+    /// [`Arg::count_with_max`]: ./struct.Arg.html#method.count_with_max
+    pub fn count_of<T: Key>(&self, id: T) -> u64 {
+        self.occurrences_of(id)
+    }
+
+    /// Reports where an argument's value came from: the command line, an environment variable
+    /// set via [`Arg::env`], or a [default value]. Returns `None` if the argument wasn't
+    /// present at runtime. This lets layered-configuration tools decide whether an explicit
+    /// user choice should override a value that merely fell back to a default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// use clap::ValueSource;
+    ///
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::new("output")
+    ///         .long("output")
+    ///         .takes_value(true))
+    ///     .arg(Arg::new("config")
+    ///         .long("config")
+    ///         .takes_value(true)
+    ///         .default_value("default.cfg"))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "--output", "out.txt"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_source("output"), Some(ValueSource::CommandLine));
+    /// assert_eq!(m.value_source("config"), Some(ValueSource::DefaultValue));
+    /// assert_eq!(m.value_source("missing"), None);
+    /// ```
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    /// [default value]: ./struct.Arg.html#method.default_value
+    pub fn value_source<T: Key>(&self, id: T) -> Option<ValueSource> {
+        self.args.get(&Id::from(id)).and_then(|a| match a.ty {
+            ValueType::CommandLine => Some(ValueSource::CommandLine),
+            ValueType::EnvVariable => Some(ValueSource::EnvVariable),
+            ValueType::DefaultValue => Some(ValueSource::DefaultValue),
+            ValueType::Unknown => None,
+        })
+    }
+
     /// Gets the starting index of the argument in respect to all other arguments. Indices are
     /// similar to argv indices, but are not exactly 1:1.
     ///
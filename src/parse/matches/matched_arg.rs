@@ -20,6 +20,8 @@ pub(crate) enum ValueType {
 pub(crate) struct MatchedArg {
     pub(crate) occurs: u64,
     pub(crate) ty: ValueType,
+    pub(crate) key_val_delim: Option<char>,
+    pub(crate) allow_invalid_utf8: bool,
     indices: Vec<usize>,
     vals: Vec<Vec<OsString>>,
 }
@@ -35,6 +37,8 @@ impl MatchedArg {
         MatchedArg {
             occurs: 0,
             ty: ValueType::Unknown,
+            key_val_delim: None,
+            allow_invalid_utf8: false,
             indices: Vec::new(),
             vals: Vec::new(),
         }
@@ -61,7 +65,9 @@ impl MatchedArg {
     }
 
     pub(crate) fn get_val(&self, index: usize) -> Option<&OsString> {
-        self.vals.get(0)?.get(index)
+        // Skips any empty group the parser may have opened while it was still
+        // expecting values (e.g. an option with an optional value)
+        self.vals_flatten().nth(index)
     }
 
     pub(crate) fn push_val(&mut self, val: OsString) {
@@ -72,6 +78,14 @@ impl MatchedArg {
         self.vals.push(vec![])
     }
 
+    pub(crate) fn take_vals(&mut self) -> Vec<Vec<OsString>> {
+        std::mem::take(&mut self.vals)
+    }
+
+    pub(crate) fn set_vals(&mut self, vals: Vec<Vec<OsString>>) {
+        self.vals = vals;
+    }
+
     pub(crate) fn append_val(&mut self, val: OsString) {
         // We assume there is always a group created before.
         self.vals.last_mut().expect(INTERNAL_ERROR_MSG).push(val)
@@ -122,8 +136,11 @@ impl MatchedArg {
     }
 
     pub(crate) fn contains_val(&self, val: &str) -> bool {
-        self.vals_flatten()
-            .any(|v| OsString::as_os_str(v) == OsStr::new(val))
+        self.contains_val_os(OsStr::new(val))
+    }
+
+    pub(crate) fn contains_val_os(&self, val: &OsStr) -> bool {
+        self.vals_flatten().any(|v| OsString::as_os_str(v) == val)
     }
 
     pub(crate) fn set_ty(&mut self, ty: ValueType) {
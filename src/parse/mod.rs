@@ -4,6 +4,8 @@ pub mod features;
 mod arg_matcher;
 pub mod matches;
 mod parser;
+#[cfg(feature = "prompt")]
+mod prompt;
 mod validator;
 
 pub(crate) use self::{
@@ -13,4 +15,4 @@ pub(crate) use self::{
     validator::Validator,
 };
 
-pub use self::matches::{ArgMatches, Indices, OsValues, Values};
+pub use self::matches::{ArgMatches, Indices, OsValues, ValueSource, Values};
@@ -365,8 +365,16 @@ impl<'help, 'app> Parser<'help, 'app> {
                 }
 
                 // Is this a new argument, or a value for previous option?
-                if self.is_new_arg(&arg_os, &needs_val_of) {
-                    if arg_os == "--" {
+                // A `last_separator` token begins trailing values just like `--` does, but
+                // needs its own check since it doesn't look like an argument.
+                let is_last_sep = self
+                    .app
+                    .args
+                    .args()
+                    .any(|a| a.last_sep.is_some_and(|sep| arg_os == sep));
+
+                if self.is_new_arg(&arg_os, &needs_val_of) || is_last_sep {
+                    if arg_os == "--" || is_last_sep {
                         debug!("Parser::get_matches_with: setting TrailingVals=true");
                         self.app.set(AS::TrailingValues);
                         continue;
@@ -606,6 +614,7 @@ impl<'help, 'app> Parser<'help, 'app> {
                 kind: ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand,
                 info: vec![],
                 source: None,
+                exit_code: None,
             });
         }
 
@@ -934,7 +943,7 @@ impl<'help, 'app> Parser<'help, 'app> {
         );
 
         if let Some(help) = self.app.find(&Id::help_hash()) {
-            if let Some(h) = help.long {
+            if let Some(h) = help.long.as_deref() {
                 if arg == h && !self.is_set(AS::NoAutoHelp) {
                     debug!("Help");
                     return Err(self.help_err(true));
@@ -943,7 +952,7 @@ impl<'help, 'app> Parser<'help, 'app> {
         }
 
         if let Some(version) = self.app.find(&Id::version_hash()) {
-            if let Some(v) = version.long {
+            if let Some(v) = version.long.as_deref() {
                 if arg == v && !self.is_set(AS::NoAutoVersion) {
                     debug!("Version");
                     return Err(self.version_err(true));
@@ -1001,6 +1010,8 @@ impl<'help, 'app> Parser<'help, 'app> {
             || self.app.after_long_help.is_some()
             || self.app.args.args().any(should_long)
             || self.app.subcommands.iter().any(|s| s.long_about.is_some())
+            || (self.app.is_set(AS::ShowHiddenArgs)
+                && self.app.args.args().any(|v| v.hide_unless_long_help))
     }
 
     fn parse_long_arg(
@@ -1102,7 +1113,8 @@ impl<'help, 'app> Parser<'help, 'app> {
                 }
 
                 // Check for trailing concatenated value
-                let i = arg_os.split(c).next().unwrap().len() + c.len_utf8();
+                let mut sep = [0; 4];
+                let i = arg_os.split(c.encode_utf8(&mut sep)).next().unwrap().len() + c.len_utf8();
                 debug!(
                     "Parser::parse_short_arg:iter:{}: i={}, arg_os={:?}",
                     c, i, arg_os
@@ -1211,7 +1223,7 @@ impl<'help, 'app> Parser<'help, 'app> {
         {
             debug!("Parser::parse_opt: More arg vals required...");
             matcher.new_val_group(&opt.id);
-            for group in self.app.groups_for_arg(&opt.id) {
+            for group in self.app.groups_for_arg_id(&opt.id) {
                 matcher.new_val_group(&group);
             }
             Ok(ParseResult::Opt(opt.id.clone()))
@@ -1235,27 +1247,48 @@ impl<'help, 'app> Parser<'help, 'app> {
             self.is_set(AS::TrailingValues),
             self.is_set(AS::DontDelimitTrailingValues)
         );
+        if arg.key_val_delim.is_some() {
+            // Record the delimiter so `ArgMatches::key_value_of` knows where to split
+            matcher.entry(&arg.id).or_default().key_val_delim = arg.key_val_delim;
+        }
+        if arg.is_set(ArgSettings::AllowInvalidUtf8) {
+            // Recorded so `ArgMatches::value_of` can decline instead of panicking
+            matcher.entry(&arg.id).or_default().allow_invalid_utf8 = true;
+        }
+        if arg.values_from_lines {
+            self.add_multiple_vals_to_arg(arg, val.split_lines(), matcher, ty, append);
+            return ParseResult::ValuesDone;
+        }
         if !(self.is_set(AS::TrailingValues) && self.is_set(AS::DontDelimitTrailingValues)) {
             if let Some(delim) = arg.val_delim {
-                let arg_split = val.split(delim);
+                let arg_split: Vec<_> = if arg.balanced_delimiters {
+                    val.split_balanced(delim)
+                } else if arg.quoted_delimiters {
+                    val.split_quoted(delim)
+                } else {
+                    val.split(delim).collect()
+                };
                 let vals = if let Some(t) = arg.terminator {
                     let mut vals = vec![];
                     for val in arg_split {
                         if t == val {
+                            if arg.keep_terminator {
+                                vals.push(val);
+                            }
                             break;
                         }
                         vals.push(val);
                     }
                     vals
                 } else {
-                    arg_split.collect()
+                    arg_split
                 };
                 let vals = vals.into_iter().map(|x| x.into_os_string()).collect();
                 self.add_multiple_vals_to_arg(arg, vals, matcher, ty, append);
                 // If there was a delimiter used or we must use the delimiter to
                 // separate the values or no more vals is needed, we're not
                 // looking for more values.
-                return if val.contains_char(delim)
+                return if val.contains_str(delim)
                     || arg.is_set(ArgSettings::RequireDelimiter)
                     || !matcher.needs_more_vals(arg)
                 {
@@ -1267,6 +1300,9 @@ impl<'help, 'app> Parser<'help, 'app> {
         }
         if let Some(t) = arg.terminator {
             if t == val {
+                if arg.keep_terminator {
+                    self.add_single_val_to_arg(arg, val.to_os_string(), matcher, ty, append);
+                }
                 return ParseResult::ValuesDone;
             }
         }
@@ -1289,7 +1325,7 @@ impl<'help, 'app> Parser<'help, 'app> {
         // If not appending, create a new val group and then append vals in.
         if !append {
             matcher.new_val_group(&arg.id);
-            for group in self.app.groups_for_arg(&arg.id) {
+            for group in self.app.groups_for_arg_id(&arg.id) {
                 matcher.new_val_group(&group);
             }
         }
@@ -1301,18 +1337,61 @@ impl<'help, 'app> Parser<'help, 'app> {
     fn add_single_val_to_arg(
         &self,
         arg: &Arg<'help>,
-        val: OsString,
+        mut val: OsString,
         matcher: &mut ArgMatcher,
         ty: ValueType,
         append: bool,
     ) {
         debug!("Parser::add_single_val_to_arg: adding val...{:?}", val);
 
+        // Rewrite the value to the canonical possible value casing before it's stored, so
+        // `value_of` returns the casing the possible values declare.
+        if arg.normalize_case && arg.is_set(ArgSettings::IgnoreCase) {
+            if let Some(val_str) = val.to_str() {
+                if let Some(pv) = arg
+                    .possible_vals
+                    .iter()
+                    .find(|pv| pv.eq_ignore_ascii_case(val_str))
+                {
+                    debug!(
+                        "Parser::add_single_val_to_arg: normalizing case to...{:?}",
+                        pv
+                    );
+                    val = OsString::from(pv);
+                }
+            }
+        }
+
+        // Values given as a possible value alias are stored under their canonical spelling.
+        if !arg.possible_val_aliases.is_empty() {
+            if let Some(val_str) = val.to_str() {
+                let canonical = arg.possible_val_aliases.iter().find_map(|&(als, canon)| {
+                    let matched = if arg.is_set(ArgSettings::IgnoreCase) {
+                        als.eq_ignore_ascii_case(val_str)
+                    } else {
+                        als == val_str
+                    };
+                    if matched {
+                        Some(canon)
+                    } else {
+                        None
+                    }
+                });
+                if let Some(canonical) = canonical {
+                    debug!(
+                        "Parser::add_single_val_to_arg: normalizing alias to...{:?}",
+                        canonical
+                    );
+                    val = OsString::from(canonical);
+                }
+            }
+        }
+
         // update the current index because each value is a distinct index to clap
         self.cur_idx.set(self.cur_idx.get() + 1);
 
         // Increment or create the group "args"
-        for group in self.app.groups_for_arg(&arg.id) {
+        for group in self.app.groups_for_arg_id(&arg.id) {
             matcher.add_val_to(&group, val.clone(), ty, append);
         }
 
@@ -1358,8 +1437,9 @@ impl<'help, 'app> Parser<'help, 'app> {
                 // argument or flag with one of the Multiple* setting
                 // enabled(which is a feature).
                 if (self.is_set(AS::AllArgsOverrideSelf) || override_self)
-                    && !overrider.is_set(ArgSettings::MultipleValues)
-                    && !overrider.is_set(ArgSettings::MultipleOccurrences)
+                    && (overrider.allow_self_override
+                        || (!overrider.is_set(ArgSettings::MultipleValues)
+                            && !overrider.is_set(ArgSettings::MultipleOccurrences)))
                     && overrider.has_switch()
                 {
                     debug!(
@@ -1409,6 +1489,72 @@ impl<'help, 'app> Parser<'help, 'app> {
             debug!("Parser::add_defaults:iter:{}:", p.name);
             self.add_value(p, matcher, ValueType::DefaultValue);
         }
+
+        // `default_value_from_arg` resolves last, once the source arg (possibly itself
+        // defaulted above) is known; the copy wins over the arg's own `default_value`, which
+        // only stays when the source is absent
+        let from_args: Vec<(Id, Id)> = self
+            .app
+            .args
+            .args()
+            .filter_map(|a| {
+                a.default_val_from
+                    .as_ref()
+                    .map(|src| (a.id.clone(), src.clone()))
+            })
+            .collect();
+        for (id, src) in from_args {
+            debug!("Parser::add_defaults: copying default for {:?} from {:?}", id, src);
+            if matcher
+                .get(&id)
+                .is_some_and(|ma| ma.ty != ValueType::DefaultValue)
+            {
+                continue;
+            }
+            let vals: Vec<OsString> = matcher
+                .get(&src)
+                .map(|ma| ma.vals_flatten().cloned().collect())
+                .unwrap_or_default();
+            if vals.is_empty() {
+                continue;
+            }
+            matcher.remove(&id);
+            self.add_multiple_vals_to_arg(&self.app[&id], vals, matcher, ValueType::DefaultValue, false);
+        }
+
+        // `default_value_from_count` likewise resolves last, once occurrences are counted; an
+        // absent source counts as zero
+        let from_counts: Vec<(Id, Id)> = self
+            .app
+            .args
+            .args()
+            .filter_map(|a| {
+                a.default_val_from_count
+                    .as_ref()
+                    .map(|src| (a.id.clone(), src.clone()))
+            })
+            .collect();
+        for (id, src) in from_counts {
+            if matcher
+                .get(&id)
+                .is_some_and(|ma| ma.ty != ValueType::DefaultValue)
+            {
+                continue;
+            }
+            let count = matcher.get(&src).map_or(0, |ma| ma.occurs);
+            debug!(
+                "Parser::add_defaults: defaulting {:?} to count of {:?} ({})",
+                id, src, count
+            );
+            matcher.remove(&id);
+            self.add_val_to_arg(
+                &self.app[&id],
+                ArgStr::new(&OsString::from(count.to_string())),
+                matcher,
+                ValueType::DefaultValue,
+                false,
+            );
+        }
     }
 
     fn add_value(&self, arg: &Arg<'help>, matcher: &mut ArgMatcher, ty: ValueType) {
@@ -1436,6 +1582,20 @@ impl<'help, 'app> Parser<'help, 'app> {
             debug!("Parser::add_value: doesn't have conditional defaults");
         }
 
+        #[cfg(feature = "tty_default")]
+        {
+            if let Some((val, is_tty)) = arg.default_val_if_tty {
+                if matcher.get(&arg.id).is_none() && is_tty() {
+                    debug!(
+                        "Parser::add_value:iter:{}: applying TTY-only default",
+                        arg.name
+                    );
+                    self.add_val_to_arg(arg, ArgStr::new(val), matcher, ty, false);
+                    return;
+                }
+            }
+        }
+
         if !arg.default_vals.is_empty() {
             debug!("Parser::add_value:iter:{}: has default vals", arg.name);
             if matcher.get(&arg.id).is_some() {
@@ -1500,7 +1660,18 @@ impl<'help, 'app> Parser<'help, 'app> {
                 if let Some((_, Some(ref val))) = a.env {
                     let val = ArgStr::new(val);
                     if a.is_set(ArgSettings::TakesValue) {
-                        self.add_val_to_arg(a, val, matcher, ValueType::EnvVariable, false);
+                        if a.env_line_delim && a.is_set(ArgSettings::MultipleValues) {
+                            // CI-style lists: one item per line, `\r\n` treated as a line break
+                            self.add_multiple_vals_to_arg(
+                                a,
+                                val.split_lines(),
+                                matcher,
+                                ValueType::EnvVariable,
+                                false,
+                            );
+                        } else {
+                            self.add_val_to_arg(a, val, matcher, ValueType::EnvVariable, false);
+                        }
                     } else {
                         self.check_for_help_and_version_str(&val)?;
                         matcher.add_index_to(&a.id, self.cur_idx.get(), ValueType::EnvVariable);
@@ -1511,11 +1682,88 @@ impl<'help, 'app> Parser<'help, 'app> {
         Ok(())
     }
 
+    /// For required args not given on the command line, asks the user for a value when stdin is
+    /// a TTY. Prompted values are added like command line ones, so they flow through the usual
+    /// validation; non-TTY contexts are left alone and fail as missing later on.
+    #[cfg(feature = "prompt")]
+    pub(crate) fn add_prompts(&mut self, matcher: &mut ArgMatcher) {
+        use crate::parse::prompt;
+
+        for a in self.app.args.args() {
+            if let Some(prompt) = a.prompt_if_missing {
+                if self.required.contains(&a.id) && !matcher.contains(&a.id) {
+                    debug!("Parser::add_prompts: prompting for {:?}", a.name);
+                    if let Some(input) = prompt::read_input(prompt) {
+                        let os_input = std::ffi::OsString::from(input);
+                        self.add_val_to_arg(
+                            a,
+                            ArgStr::new(&os_input),
+                            matcher,
+                            ValueType::CommandLine,
+                            false,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Replaces each value of an opted-in arg that starts with `@` by the whitespace separated
+    /// contents of the referenced file. `@@` escapes a literal leading `@`.
+    pub(crate) fn expand_at_files(&self, matcher: &mut ArgMatcher) -> ClapResult<()> {
+        debug!("Parser::expand_at_files");
+
+        for a in self.app.args.args().filter(|a| a.expand_at_files) {
+            if let Some(ma) = matcher.0.args.get_mut(&a.id) {
+                let mut expanded = Vec::new();
+                for group in ma.take_vals() {
+                    let mut new_group = Vec::with_capacity(group.len());
+                    for val in group {
+                        match val.to_str() {
+                            Some(s) if s.starts_with("@@") => {
+                                new_group.push(OsString::from(&s[1..]));
+                            }
+                            Some(s) if s.starts_with('@') => {
+                                let path = &s[1..];
+                                debug!("Parser::expand_at_files: reading {:?}", path);
+                                let contents = std::fs::read_to_string(path).map_err(|e| {
+                                    ClapError::with_description(
+                                        format!("Couldn't read argument file '{}': {}", path, e),
+                                        ErrorKind::Io,
+                                    )
+                                })?;
+                                new_group.extend(contents.split_whitespace().map(OsString::from));
+                            }
+                            _ => new_group.push(val),
+                        }
+                    }
+                    expanded.push(new_group);
+                }
+                ma.set_vals(expanded);
+            }
+        }
+        Ok(())
+    }
+
+    /// Prints the deprecation message of every deprecated arg that was actually used on the
+    /// command line to `stderr`. Deprecated args keep matching and storing values normally.
+    pub(crate) fn warn_deprecated(&self, matcher: &ArgMatcher) {
+        debug!("Parser::warn_deprecated");
+
+        for a in self.app.args.args().filter(|a| a.deprecated.is_some()) {
+            if let Some(ma) = matcher.0.args.get(&a.id) {
+                if ma.ty == ValueType::CommandLine {
+                    eprintln!("warning: {}", a.deprecated.unwrap());
+                }
+            }
+        }
+    }
+
     /// Increase occurrence of specific argument and the grouped arg it's in.
     fn inc_occurrence_of_arg(&self, matcher: &mut ArgMatcher, arg: &Arg<'help>) {
         matcher.inc_occurrence_of(&arg.id);
         // Increment or create the group "args"
-        for group in self.app.groups_for_arg(&arg.id) {
+        for group in self.app.groups_for_arg_id(&arg.id) {
             matcher.inc_occurrence_of(&group);
         }
     }
@@ -1597,6 +1845,7 @@ impl<'help, 'app> Parser<'help, 'app> {
                 kind: ErrorKind::DisplayHelp,
                 info: vec![],
                 source: None,
+                exit_code: None,
             },
         }
     }
@@ -1612,6 +1861,7 @@ impl<'help, 'app> Parser<'help, 'app> {
             kind: ErrorKind::DisplayVersion,
             info: vec![],
             source: None,
+            exit_code: None,
         }
     }
 }
@@ -0,0 +1,57 @@
+//! Reading values for missing required arguments interactively from stdin.
+
+use std::io::{self, BufRead, Write};
+
+/// Prompts on stderr and reads one line from stdin, provided stdin is a TTY.
+/// Returns `None` when stdin is not a TTY (or on read failure) so the caller
+/// can fall back to the usual missing-argument error.
+pub(crate) fn read_input(prompt: &str) -> Option<String> {
+    let is_tty = atty::is(atty::Stream::Stdin);
+    let stdin = io::stdin();
+    let mut lock = stdin.lock();
+    read_input_from(&mut lock, is_tty, prompt)
+}
+
+fn read_input_from(reader: &mut dyn BufRead, is_tty: bool, prompt: &str) -> Option<String> {
+    if !is_tty {
+        return None;
+    }
+    eprint!("{}: ", prompt);
+    io::stderr().flush().ok();
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    while line.ends_with('\n') || line.ends_with('\r') {
+        line.pop();
+    }
+    Some(line)
+}
+
+#[cfg(test)]
+mod test {
+    use super::read_input_from;
+    use std::io::Cursor;
+
+    #[test]
+    fn reads_line_when_tty() {
+        let mut input = Cursor::new(b"some value\n".to_vec());
+        assert_eq!(
+            read_input_from(&mut input, true, "Enter value"),
+            Some("some value".into())
+        );
+    }
+
+    #[test]
+    fn strips_crlf() {
+        let mut input = Cursor::new(b"some value\r\n".to_vec());
+        assert_eq!(
+            read_input_from(&mut input, true, "Enter value"),
+            Some("some value".into())
+        );
+    }
+
+    #[test]
+    fn falls_back_when_not_tty() {
+        let mut input = Cursor::new(b"some value\n".to_vec());
+        assert_eq!(read_input_from(&mut input, false, "Enter value"), None);
+    }
+}
@@ -1,6 +1,6 @@
 // Internal
 use crate::{
-    build::{AppSettings as AS, Arg, ArgSettings},
+    build::{arg::RequiresPredicate, AppSettings as AS, Arg, ArgSettings},
     output::Usage,
     parse::{
         errors::{Error, ErrorKind, Result as ClapResult},
@@ -10,6 +10,8 @@ use crate::{
     INTERNAL_ERROR_MSG, INVALID_UTF8,
 };
 
+use std::ffi::OsString;
+
 pub(crate) struct Validator<'help, 'app, 'parser> {
     p: &'parser mut Parser<'help, 'app>,
     c: ChildGraph<Id>,
@@ -33,6 +35,10 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
         let mut reqs_validated = false;
         self.p.add_env(matcher)?;
         self.p.add_defaults(matcher);
+        #[cfg(feature = "prompt")]
+        self.p.add_prompts(matcher);
+        self.p.expand_at_files(matcher)?;
+        self.p.warn_deprecated(matcher);
         if let ParseResult::Opt(a) = needs_val_of {
             debug!("Validator::validate: needs_val_of={:?}", a);
             self.validate_required(matcher)?;
@@ -63,6 +69,7 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                 kind: ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand,
                 info: vec![],
                 source: None,
+                exit_code: None,
             });
         }
         self.validate_conflicts(matcher)?;
@@ -71,10 +78,69 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
             self.validate_required_unless(matcher)?;
         }
         self.validate_matched_args(matcher)?;
+        self.validate_cross_field(matcher)?;
 
         Ok(())
     }
 
+    // Deferred validators run last, once every arg has been matched, so they can read other
+    // args' values out of the partial matches
+    fn validate_cross_field(&self, matcher: &ArgMatcher) -> ClapResult<()> {
+        debug!("Validator::validate_cross_field");
+        for arg in self.p.app.args.args() {
+            if let Some(ref vtor) = arg.validator_matches {
+                if let Some(ma) = matcher.0.args.get(&arg.id) {
+                    let mut vtor = vtor.lock().unwrap();
+                    for val in ma.vals_flatten() {
+                        let val_str = val.to_string_lossy();
+                        if let Err(e) = vtor(&val_str, &matcher.0) {
+                            debug!("error");
+                            return Err(Error::value_validation(
+                                arg.to_string(),
+                                val_str.into_owned(),
+                                e,
+                                self.p.app.color(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn run_str_validator(&self, arg: &Arg, val: &OsString) -> ClapResult<()> {
+        if let Some(ref vtor) = arg.validator {
+            debug!("Validator::run_str_validator: checking validator...");
+            let mut vtor = vtor.lock().unwrap();
+            match val.to_str() {
+                Some(val_str) => {
+                    if let Err(e) = vtor(val_str) {
+                        debug!("error");
+                        return Err(Error::value_validation(
+                            arg.to_string(),
+                            val_str.to_string(),
+                            e,
+                            self.p.app.color(),
+                        ));
+                    } else {
+                        debug!("good");
+                    }
+                }
+                None => {
+                    debug!("Validator::run_str_validator: value is not valid UTF-8");
+                    return Err(Error::value_validation(
+                        arg.to_string(),
+                        val.to_string_lossy().into(),
+                        "value is not valid UTF-8".into(),
+                        self.p.app.color(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn validate_arg_values(
         &self,
         arg: &Arg,
@@ -82,8 +148,14 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
         matcher: &ArgMatcher,
     ) -> ClapResult<()> {
         debug!("Validator::validate_arg_values: arg={:?}", arg.name);
-        for val in ma.vals_flatten() {
-            if self.p.is_set(AS::StrictUtf8) && val.to_str().is_none() {
+        let num_vals = ma.num_vals();
+        for (idx, val) in ma.vals_flatten().enumerate() {
+            // `validate_last_only` skips the str validator for everything but the final value
+            let run_str_vtor = !arg.validate_last_only || idx + 1 == num_vals;
+            if self.p.is_set(AS::StrictUtf8)
+                && !arg.is_set(ArgSettings::AllowInvalidUtf8)
+                && val.to_str().is_none()
+            {
                 debug!(
                     "Validator::validate_arg_values: invalid UTF-8 found in val {:?}",
                     val
@@ -93,6 +165,11 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                     self.p.app.color(),
                 ));
             }
+            // An explicit opt-in runs the cheap str validator before the possible-values
+            // lookup, otherwise the possible-values check keeps its historical first spot
+            if arg.validate_before_possible_vals && run_str_vtor {
+                self.run_str_validator(arg, val)?;
+            }
             if !arg.possible_vals.is_empty() {
                 debug!(
                     "Validator::validate_arg_values: possible_vals={:?}",
@@ -103,8 +180,16 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                     arg.possible_vals
                         .iter()
                         .any(|pv| pv.eq_ignore_ascii_case(&val_str))
+                        || arg
+                            .possible_val_aliases
+                            .iter()
+                            .any(|(als, _)| als.eq_ignore_ascii_case(&val_str))
                 } else {
                     arg.possible_vals.contains(&&*val_str)
+                        || arg
+                            .possible_val_aliases
+                            .iter()
+                            .any(|(als, _)| *als == &*val_str)
                 };
                 if !ok {
                     let used: Vec<Id> = matcher
@@ -137,14 +222,28 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                 ));
             }
 
-            if let Some(ref vtor) = arg.validator {
-                debug!("Validator::validate_arg_values: checking validator...");
+            if let Some(delim) = arg.key_val_delim {
+                debug!("Validator::validate_arg_values: checking key-value delimiter...");
+                let val_str = val.to_string_lossy();
+                if !val_str.contains(delim) {
+                    return Err(Error::value_validation(
+                        arg.to_string(),
+                        val_str.to_string(),
+                        format!("'{}' isn't a KEY{}VALUE pair", val_str, delim).into(),
+                        self.p.app.color(),
+                    ));
+                }
+            }
+            // `validator_os` runs first on the raw `OsStr`; only if it passes (and the value is
+            // valid UTF-8) does the str `validator` get a look
+            if let Some(ref vtor) = arg.validator_os {
+                debug!("Validator::validate_arg_values: checking validator_os...");
                 let mut vtor = vtor.lock().unwrap();
-                if let Err(e) = vtor(&*val.to_string_lossy()) {
+                if let Err(e) = vtor(val) {
                     debug!("error");
                     return Err(Error::value_validation(
                         arg.to_string(),
-                        val.to_string_lossy().to_string(),
+                        val.to_string_lossy().into(),
                         e,
                         self.p.app.color(),
                     ));
@@ -152,14 +251,18 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                     debug!("good");
                 }
             }
-            if let Some(ref vtor) = arg.validator_os {
-                debug!("Validator::validate_arg_values: checking validator_os...");
+            if !arg.validate_before_possible_vals && run_str_vtor {
+                self.run_str_validator(arg, val)?;
+            }
+            if let Some(ref vtor) = arg.validator_named {
+                debug!("Validator::validate_arg_values: checking validator_named...");
                 let mut vtor = vtor.lock().unwrap();
-                if let Err(e) = vtor(val) {
+                let val_str = val.to_string_lossy();
+                if let Err(e) = vtor(arg.name, &val_str) {
                     debug!("error");
                     return Err(Error::value_validation(
                         arg.to_string(),
-                        val.to_string_lossy().into(),
+                        val_str.into_owned(),
                         e,
                         self.p.app.color(),
                     ));
@@ -167,7 +270,87 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                     debug!("good");
                 }
             }
+            if let Some(ref vtor) = arg.validator_warn {
+                debug!("Validator::validate_arg_values: checking validator_warn...");
+                let mut vtor = vtor.lock().unwrap();
+                if let Some(msg) = vtor(&val.to_string_lossy()) {
+                    // Suspicious but not invalid: warn and keep parsing
+                    eprintln!("warning: {}", msg);
+                }
+            }
+            if let Some(ref vtor) = arg.validator_suggestions {
+                debug!("Validator::validate_arg_values: checking validator_with_suggestions...");
+                let mut vtor = vtor.lock().unwrap();
+                let val_str = val.to_string_lossy();
+                if let Err((msg, suggestions)) = vtor(&val_str) {
+                    debug!("error");
+                    return Err(Error::value_validation_with_suggestions(
+                        arg.to_string(),
+                        val_str.into_owned(),
+                        msg,
+                        &suggestions,
+                        self.p.app.color(),
+                    ));
+                } else {
+                    debug!("good");
+                }
+            }
+        }
+
+        if arg.is_set(ArgSettings::RequireIncreasingValues) {
+            debug!("Validator::validate_arg_values: checking values are increasing...");
+            let mut prev: Option<(String, f64)> = None;
+            for val in ma.vals_flatten() {
+                let val_str = val.to_string_lossy();
+                let num = match val_str.parse::<f64>() {
+                    // NaN compares false against everything, so it would slip through the
+                    // ordering check below; treat it (and the infinities) as not a number
+                    Ok(num) if num.is_finite() => num,
+                    _ => {
+                        return Err(Error::value_validation(
+                            arg.to_string(),
+                            val_str.to_string(),
+                            format!("The value '{}' is not a number", val_str).into(),
+                            self.p.app.color(),
+                        ));
+                    }
+                };
+                if let Some((prev_str, prev_num)) = prev {
+                    if num <= prev_num {
+                        return Err(Error::value_validation(
+                            arg.to_string(),
+                            val_str.to_string(),
+                            format!(
+                                "The values are not strictly increasing ('{}' is followed by '{}')",
+                                prev_str, val_str
+                            )
+                            .into(),
+                            self.p.app.color(),
+                        ));
+                    }
+                }
+                prev = Some((val_str.to_string(), num));
+            }
         }
+
+        if let Some(ref vtor) = arg.validator_all {
+            debug!("Validator::validate_arg_values: checking validator_all...");
+            let vals: Vec<_> = ma.vals_flatten().map(|v| v.to_string_lossy()).collect();
+            let val_strs: Vec<&str> = vals.iter().map(|v| &**v).collect();
+            let mut vtor = vtor.lock().unwrap();
+            if let Err(e) = vtor(&val_strs) {
+                debug!("error");
+                return Err(Error::value_validation(
+                    arg.to_string(),
+                    val_strs.join(" "),
+                    e,
+                    self.p.app.color(),
+                ));
+            } else {
+                debug!("good");
+            }
+        }
+
         Ok(())
     }
 
@@ -245,9 +428,38 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
         panic!("{}", INTERNAL_ERROR_MSG);
     }
 
+    fn validate_subcommand_conflicts(&mut self, matcher: &mut ArgMatcher) -> ClapResult<()> {
+        debug!("Validator::validate_subcommand_conflicts");
+        if let Some(s) = matcher.subcommand_name() {
+            for arg in self.p.app.args.args() {
+                if !arg.blacklisted_subcommands.contains(&s) {
+                    continue;
+                }
+                // A global arg supplied after the subcommand is recorded in the
+                // subcommand's matches rather than ours, so check every nested level
+                let mut used = matcher.get(&arg.id).is_some_and(|ma| ma.occurs > 0);
+                let mut sub = matcher.0.subcommand.as_deref();
+                while let (false, Some(sc)) = (used, sub) {
+                    used = sc.matches.args.get(&arg.id).is_some_and(|ma| ma.occurs > 0);
+                    sub = sc.matches.subcommand.as_deref();
+                }
+                if used {
+                    return Err(Error::argument_conflict(
+                        arg,
+                        Some(s.to_string()),
+                        Usage::new(self.p).create_usage_with_title(&[]),
+                        self.p.app.color(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn validate_conflicts(&mut self, matcher: &mut ArgMatcher) -> ClapResult<()> {
         debug!("Validator::validate_conflicts");
         self.validate_exclusive(matcher)?;
+        self.validate_subcommand_conflicts(matcher)?;
         self.gather_conflicts(matcher);
 
         for name in self.c.iter() {
@@ -271,10 +483,18 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
 
                 let conf_with_arg = g.conflicts.iter().any(|x| matcher.contains(x));
 
-                let arg_conf_with_gr = matcher
-                    .arg_names()
-                    .filter_map(|x| self.p.app.find(x))
-                    .any(|x| x.blacklist.iter().any(|c| *c == g.id));
+                // An arg conflicting with a group only errs once a member of that group was
+                // actually used alongside it
+                let arg_conf_with_gr = self
+                    .p
+                    .app
+                    .unroll_args_in_group(&g.id)
+                    .iter()
+                    .any(|a| matcher.contains(a))
+                    && matcher
+                        .arg_names()
+                        .filter_map(|x| self.p.app.find(x))
+                        .any(|x| x.blacklist.contains(&g.id));
 
                 should_err = conf_with_self || conf_with_arg || arg_conf_with_gr;
             } else if let Some(ma) = matcher.get(name) {
@@ -349,7 +569,7 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                 // args in that group to the conflicts, as well as any args those args conflict
                 // with
 
-                for grp in self.p.app.groups_for_arg(&name) {
+                for grp in self.p.app.groups_for_arg_id(name) {
                     if let Some(g) = self
                         .p
                         .app
@@ -396,6 +616,7 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
 
     fn validate_matched_args(&self, matcher: &mut ArgMatcher) -> ClapResult<()> {
         debug!("Validator::validate_matched_args");
+        let mut value_errors = vec![];
         for (name, ma) in matcher.iter() {
             debug!(
                 "Validator::validate_matched_args:iter:{:?}: vals={:#?}",
@@ -404,7 +625,20 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
             );
             if let Some(arg) = self.p.app.find(name) {
                 self.validate_arg_num_vals(arg, ma)?;
-                self.validate_arg_values(arg, ma, matcher)?;
+                if let Err(mut e) = self.validate_arg_values(arg, ma, matcher) {
+                    if e.exit_code.is_none() {
+                        e.exit_code = arg.validation_exit_code;
+                    }
+                    // Under AggregateValueErrors value errors are collected across all args and
+                    // reported together once the loop finishes.
+                    if self.p.is_set(AS::AggregateValueErrors)
+                        && e.kind == ErrorKind::ValueValidation
+                    {
+                        value_errors.push(e);
+                    } else {
+                        return Err(e);
+                    }
+                }
                 self.validate_arg_requires(arg, ma, matcher)?;
                 self.validate_arg_num_occurs(arg, ma)?;
             } else {
@@ -420,6 +654,87 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                 }
             }
         }
+        if value_errors.len() == 1 {
+            return Err(value_errors.pop().unwrap());
+        }
+        if !value_errors.is_empty() {
+            return Err(Error::aggregated_value_validation(
+                value_errors,
+                self.p.app.color(),
+            ));
+        }
+        // Mappers run only once everything above accepted the input, so they always see fully
+        // validated values. The per-value `map_os` transform runs before the whole-list
+        // `map_values` rewrite.
+        for arg in self.p.app.args.args() {
+            if let Some(ref mapper) = arg.value_mapper_os {
+                if let Some(ma) = matcher.0.args.get_mut(&arg.id) {
+                    debug!(
+                        "Validator::validate_matched_args: os-mapping vals of {:?}",
+                        arg.name
+                    );
+                    let mut mapper = mapper.lock().unwrap();
+                    let mut mapped = Vec::new();
+                    for group in ma.take_vals() {
+                        let mut new_group = Vec::with_capacity(group.len());
+                        for val in group {
+                            match mapper(&val) {
+                                Ok(new_val) => new_group.push(new_val),
+                                Err(e) => {
+                                    return Err(Error::value_validation(
+                                        arg.to_string(),
+                                        val.to_string_lossy().into(),
+                                        e,
+                                        self.p.app.color(),
+                                    ));
+                                }
+                            }
+                        }
+                        mapped.push(new_group);
+                    }
+                    ma.set_vals(mapped);
+                }
+            }
+        }
+        for arg in self.p.app.args.args() {
+            if let Some(ref mapper) = arg.value_mapper {
+                if let Some(ma) = matcher.0.args.get_mut(&arg.id) {
+                    debug!("Validator::validate_matched_args: mapping vals of {:?}", arg.name);
+                    let groups = ma.take_vals();
+                    let group_lens: Vec<usize> = groups.iter().map(|g| g.len()).collect();
+                    let old_vals: Vec<OsString> = groups.into_iter().flatten().collect();
+                    let lossy: Vec<String> = old_vals
+                        .iter()
+                        .map(|v| v.to_string_lossy().into_owned())
+                        .collect();
+                    let mapped = mapper.lock().unwrap()(lossy.clone());
+                    // Keep the original `OsString` for any value the mapper passed through
+                    // unchanged, so non-UTF8 input survives a mapper that doesn't touch it
+                    let mapped: Vec<OsString> = mapped
+                        .into_iter()
+                        .map(|val| match lossy.iter().position(|l| *l == val) {
+                            Some(i) => old_vals[i].clone(),
+                            None => OsString::from(val),
+                        })
+                        .collect();
+                    if mapped.len() == old_vals.len() {
+                        // Same number of values out as in: restore the per-occurrence
+                        // grouping so `ArgMatches::grouped_values_of` is unaffected
+                        let mut mapped = mapped.into_iter();
+                        ma.set_vals(
+                            group_lens
+                                .iter()
+                                .map(|&len| mapped.by_ref().take(len).collect())
+                                .collect(),
+                        );
+                    } else {
+                        // The mapper changed the count (e.g. dedup), so the old grouping
+                        // no longer applies
+                        ma.set_vals(vec![mapped]);
+                    }
+                }
+            }
+        }
         Ok(())
     }
 
@@ -428,6 +743,23 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
             "Validator::validate_arg_num_occurs: {:?}={}",
             a.name, ma.occurs
         );
+        // An explicit occurrence cap takes precedence over the generic multiple-usage
+        // check so the more specific error is the one reported.
+        if let Some(max_occurs) = a.max_occurs {
+            debug!(
+                "Validator::validate_arg_num_occurs: max_occurs set...{}",
+                max_occurs
+            );
+            if ma.occurs > max_occurs {
+                return Err(Error::too_many_occurrences(
+                    a,
+                    max_occurs,
+                    ma.occurs,
+                    Usage::new(self.p).create_usage_with_title(&[]),
+                    self.p.app.color(),
+                ));
+            }
+        }
         // Occurence of positional argument equals to number of values rather
         // than number of grouped values.
         if ma.occurs > 1 && !a.is_set(ArgSettings::MultipleOccurrences) && !a.is_positional() {
@@ -518,14 +850,32 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
         matcher: &ArgMatcher,
     ) -> ClapResult<()> {
         debug!("Validator::validate_arg_requires:{:?}", a.name);
-        for (val, name) in &a.requires {
-            if let Some(val) = val {
-                let missing_req = |v| v == val && !matcher.contains(&name);
-                if ma.vals_flatten().any(missing_req) {
-                    return self.missing_required_error(matcher, vec![a.id.clone()]);
+        // By default a target that's present only through its default value satisfies the
+        // requirement; `requires_explicit` narrows this to values the user actually supplied
+        let target_present = |id: &Id| {
+            matcher
+                .get(id)
+                .is_some_and(|t| !a.requires_explicit || t.ty != ValueType::DefaultValue)
+        };
+        for (pred, name) in &a.requires {
+            match pred {
+                RequiresPredicate::Equals(val) => {
+                    let missing_req = |v| v == *val && !target_present(name);
+                    if ma.vals_flatten().any(missing_req) {
+                        return self.missing_required_error(matcher, vec![a.id.clone()]);
+                    }
+                }
+                RequiresPredicate::NotEquals(val) => {
+                    let missing_req = |v| v != *val && !target_present(name);
+                    if ma.vals_flatten().any(missing_req) {
+                        return self.missing_required_error(matcher, vec![a.id.clone()]);
+                    }
+                }
+                RequiresPredicate::Always => {
+                    if !target_present(name) {
+                        return self.missing_required_error(matcher, vec![name.clone()]);
+                    }
                 }
-            } else if !matcher.contains(&name) {
-                return self.missing_required_error(matcher, vec![name.clone()]);
             }
         }
         Ok(())
@@ -585,6 +935,19 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
             if match_all && !a.r_ifs_all.is_empty() && !matcher.contains(&a.id) {
                 return self.missing_required_error(matcher, vec![a.id.clone()]);
             }
+
+            for group in &a.r_if_groups {
+                if !matcher.contains(&a.id)
+                    && self
+                        .p
+                        .app
+                        .unroll_args_in_group(group)
+                        .iter()
+                        .any(|arg| matcher.contains(arg))
+                {
+                    return self.missing_required_error(matcher, vec![a.id.clone()]);
+                }
+            }
         }
         Ok(())
     }
@@ -32,9 +32,8 @@ impl<'a> ArgStr<'a> {
         self.0.contains(&byte)
     }
 
-    pub(crate) fn contains_char(&self, ch: char) -> bool {
-        let mut bytes = [0; 4];
-        let bytes = ch.encode_utf8(&mut bytes).as_bytes();
+    pub(crate) fn contains_str(&self, s: &str) -> bool {
+        let bytes = s.as_bytes();
         for i in 0..self.0.len().saturating_sub(bytes.len() - 1) {
             if self.0[i..].starts_with(bytes) {
                 return true;
@@ -88,16 +87,95 @@ impl<'a> ArgStr<'a> {
         )
     }
 
-    pub(crate) fn split(&self, ch: char) -> ArgSplit<'_> {
-        let mut sep = [0; 4];
+    pub(crate) fn split<'s>(&self, sep: &'s str) -> ArgSplit<'_, 's> {
         ArgSplit {
-            sep_len: ch.encode_utf8(&mut sep).as_bytes().len(),
-            sep,
+            sep: sep.as_bytes(),
             val: &self.0,
             pos: 0,
         }
     }
 
+    // Like `split`, but a separator inside a pair of matching quotes (`"` or `'`) does not
+    // split; one pair of outermost matching quotes is stripped from each piece. An unclosed
+    // quote suppresses splitting for the remainder of the value and is kept literally.
+    pub(crate) fn split_quoted(&self, sep: &str) -> Vec<ArgStr<'_>> {
+        let sep = sep.as_bytes();
+        let mut vals = vec![];
+        let mut start = 0;
+        let mut pos = 0;
+        let mut quote: Option<u8> = None;
+        while pos < self.0.len() {
+            let b = self.0[pos];
+            match quote {
+                Some(q) if b == q => quote = None,
+                None if b == b'"' || b == b'\'' => quote = Some(b),
+                None if self.0[pos..].starts_with(sep) => {
+                    vals.push(Self::unquote(&self.0[start..pos]));
+                    pos += sep.len();
+                    start = pos;
+                    continue;
+                }
+                _ => {}
+            }
+            pos += 1;
+        }
+        vals.push(Self::unquote(&self.0[start..]));
+        vals
+    }
+
+    // Splits on `sep` only at bracket nesting depth zero; `()`, `[]` and `{}` all share one
+    // depth counter and the brackets themselves are kept literally
+    pub(crate) fn split_balanced(&self, sep: &str) -> Vec<ArgStr<'_>> {
+        let sep = sep.as_bytes();
+        let mut vals = vec![];
+        let mut start = 0;
+        let mut pos = 0;
+        let mut depth = 0usize;
+        while pos < self.0.len() {
+            match self.0[pos] {
+                b'(' | b'[' | b'{' => depth += 1,
+                b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+                _ if depth == 0 && self.0[pos..].starts_with(sep) => {
+                    vals.push(ArgStr(Cow::Borrowed(&self.0[start..pos])));
+                    pos += sep.len();
+                    start = pos;
+                    continue;
+                }
+                _ => {}
+            }
+            pos += 1;
+        }
+        vals.push(ArgStr(Cow::Borrowed(&self.0[start..])));
+        vals
+    }
+
+    // Splits into non-empty lines in the manner of `str::lines`: a trailing carriage
+    // return belongs to the line break, so `\r\n` separated input works too
+    pub(crate) fn split_lines(&self) -> Vec<OsString> {
+        self.split("\n")
+            .map(|line| {
+                if line.as_raw_bytes().last() == Some(&b'\r') {
+                    line.split_at_unchecked(line.len() - 1).0.into_os_string()
+                } else {
+                    line.into_os_string()
+                }
+            })
+            .filter(|line| !line.is_empty())
+            .collect()
+    }
+
+    // Strips one pair of outermost matching quotes, if present
+    fn unquote(bytes: &[u8]) -> ArgStr<'_> {
+        if bytes.len() >= 2
+            && (bytes[0] == b'"' || bytes[0] == b'\'')
+            && bytes[bytes.len() - 1] == bytes[0]
+        {
+            ArgStr(Cow::Borrowed(&bytes[1..bytes.len() - 1]))
+        } else {
+            ArgStr(Cow::Borrowed(bytes))
+        }
+    }
+
     #[allow(dead_code)]
     pub(crate) fn as_raw_bytes(&self) -> &[u8] {
         &self.0
@@ -159,14 +237,13 @@ impl<'a> PartialEq<ArgStr<'a>> for &str {
 }
 
 #[derive(Clone, Debug)]
-pub(crate) struct ArgSplit<'a> {
-    sep: [u8; 4],
-    sep_len: usize,
+pub(crate) struct ArgSplit<'a, 's> {
+    sep: &'s [u8],
     val: &'a [u8],
     pos: usize,
 }
 
-impl<'a> Iterator for ArgSplit<'a> {
+impl<'a, 's> Iterator for ArgSplit<'a, 's> {
     type Item = ArgStr<'a>;
 
     fn next(&mut self) -> Option<ArgStr<'a>> {
@@ -177,9 +254,9 @@ impl<'a> Iterator for ArgSplit<'a> {
         }
         let start = self.pos;
         while self.pos < self.val.len() {
-            if self.val[self.pos..].starts_with(&self.sep[..self.sep_len]) {
+            if self.val[self.pos..].starts_with(self.sep) {
                 let arg = ArgStr(Cow::Borrowed(&self.val[start..self.pos]));
-                self.pos += self.sep_len;
+                self.pos += self.sep.len();
                 return Some(arg);
             }
             self.pos += 1;
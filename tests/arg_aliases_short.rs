@@ -204,3 +204,62 @@ fn visible_short_arg_aliases_help_output() {
         false
     ));
 }
+
+#[test]
+fn get_all_short_flags_includes_hidden_aliases() {
+    let a = Arg::new("opt")
+        .short('o')
+        .short_alias('p')
+        .visible_short_alias('q');
+    assert_eq!(a.get_all_short_flags(), ['o', 'p', 'q']);
+
+    let a = Arg::new("opt").short_alias('p');
+    assert_eq!(a.get_all_short_flags(), ['p']);
+}
+
+static SHORTS_HELP: &str = "prog 
+
+USAGE:
+    prog [FLAGS]
+
+FLAGS:
+    -?               Prints the query [short aliases: q, Q]
+    -h, --help       Prints help information
+    -V, --version    Prints version information";
+
+#[test]
+fn shorts_all_listed_in_help() {
+    let app = App::new("prog").arg(Arg::new("query").shorts(&['?', 'q', 'Q']).about("Prints the query"));
+    assert!(utils::compare_output(app, "prog --help", SHORTS_HELP, false));
+}
+
+#[test]
+fn shorts_each_matches() {
+    for flag in &["-?", "-q", "-Q"] {
+        let m = App::new("prog")
+            .arg(Arg::new("query").shorts(&['?', 'q', 'Q']))
+            .try_get_matches_from(vec!["prog", flag]);
+        assert!(m.is_ok(), "{:?}", m.unwrap_err());
+        assert!(m.unwrap().is_present("query"));
+    }
+}
+
+#[test]
+fn short_case_insensitive_matches_both_cases() {
+    for flag in &["-F", "-f"] {
+        let m = App::new("prog")
+            .arg(Arg::new("force").short('F').short_case_insensitive(true))
+            .try_get_matches_from(vec!["prog", flag]);
+        assert!(m.is_ok(), "{:?}", m.unwrap_err());
+        assert!(m.unwrap().is_present("force"));
+    }
+}
+
+#[test]
+fn short_case_insensitive_non_alphabetic_noop() {
+    let m = App::new("prog")
+        .arg(Arg::new("query").short('?').short_case_insensitive(true))
+        .try_get_matches_from(vec!["prog", "-?"]);
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    assert!(m.unwrap().is_present("query"));
+}
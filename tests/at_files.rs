@@ -0,0 +1,71 @@
+use clap::{App, Arg, ErrorKind};
+
+use std::fs;
+use std::path::PathBuf;
+
+fn arg_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("clap_at_files_{}_{}", std::process::id(), name));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn at_file_expands_to_multiple_values() {
+    let file = arg_file("multi", "alpha beta\ngamma\n");
+    let m = App::new("prog")
+        .arg(
+            Arg::new("vals")
+                .long("vals")
+                .takes_value(true)
+                .multiple_values(true)
+                .expand_at_files(true),
+        )
+        .try_get_matches_from(vec![
+            "prog".to_string(),
+            "--vals".to_string(),
+            format!("@{}", file.display()),
+        ])
+        .unwrap();
+    fs::remove_file(&file).unwrap();
+    assert_eq!(
+        m.values_of("vals").unwrap().collect::<Vec<_>>(),
+        ["alpha", "beta", "gamma"]
+    );
+}
+
+#[test]
+fn at_file_missing_file_is_error() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("vals")
+                .long("vals")
+                .takes_value(true)
+                .expand_at_files(true),
+        )
+        .try_get_matches_from(vec!["prog", "--vals", "@/this/path/does/not/exist"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::Io);
+}
+
+#[test]
+fn at_file_double_at_escapes_literal() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("vals")
+                .long("vals")
+                .takes_value(true)
+                .expand_at_files(true),
+        )
+        .try_get_matches_from(vec!["prog", "--vals", "@@handle"])
+        .unwrap();
+    assert_eq!(m.value_of("vals"), Some("@handle"));
+}
+
+#[test]
+fn at_file_not_expanded_without_setting() {
+    let m = App::new("prog")
+        .arg(Arg::new("vals").long("vals").takes_value(true))
+        .try_get_matches_from(vec!["prog", "--vals", "@handle"])
+        .unwrap();
+    assert_eq!(m.value_of("vals"), Some("@handle"));
+}
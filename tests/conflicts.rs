@@ -261,3 +261,149 @@ fn conflicts_with_default() {
     assert_eq!(m.value_of("opt"), Some("default"));
     assert!(m.is_present("flag"));
 }
+
+#[test]
+fn cleared_conflicts_allow_both() {
+    let result = App::new("conflict")
+        .arg(
+            Arg::from("-f, --flag 'some flag'")
+                .conflicts_with("other")
+                .clear_conflicts(),
+        )
+        .arg(Arg::from("-o, --other 'some other flag'"))
+        .try_get_matches_from(vec!["myprog", "-f", "-o"]);
+
+    assert!(result.is_ok(), "{:?}", result.unwrap_err());
+}
+
+#[test]
+fn conflicts_reciprocated_at_build() {
+    let mut app = App::new("conflict")
+        .arg(Arg::from("-f, --flag 'some flag'").conflicts_with("other"))
+        .arg(Arg::from("-o, --other 'some other flag'"));
+    app._build();
+
+    let flag = app.get_arguments().find(|a| a.get_name() == "flag").unwrap();
+    let conflicts = app.get_arg_conflicts_with(flag);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].get_name(), "other");
+
+    let other = app
+        .get_arguments()
+        .find(|a| a.get_name() == "other")
+        .unwrap();
+    let conflicts = app.get_arg_conflicts_with(other);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].get_name(), "flag");
+}
+
+#[test]
+fn conflicts_with_subcommand_errors_on_named_subcommand() {
+    let result = App::new("prog")
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .conflicts_with_subcommand("commit"),
+        )
+        .subcommand(App::new("commit"))
+        .subcommand(App::new("status"))
+        .try_get_matches_from(vec!["prog", "--dry-run", "commit"]);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind, ErrorKind::ArgumentConflict);
+}
+
+#[test]
+fn conflicts_with_subcommand_allows_other_subcommands() {
+    let result = App::new("prog")
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .conflicts_with_subcommand("commit"),
+        )
+        .subcommand(App::new("commit"))
+        .subcommand(App::new("status"))
+        .try_get_matches_from(vec!["prog", "--dry-run", "status"]);
+
+    assert!(result.is_ok(), "{:?}", result.unwrap_err());
+}
+
+#[test]
+fn conflicts_with_subcommand_global_arg_after_subcommand() {
+    let result = App::new("prog")
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .global(true)
+                .conflicts_with_subcommand("commit"),
+        )
+        .subcommand(App::new("commit"))
+        .try_get_matches_from(vec!["prog", "commit", "--dry-run"]);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind, ErrorKind::ArgumentConflict);
+}
+
+#[test]
+fn conflicts_with_subcommand_unused_arg_is_fine() {
+    let result = App::new("prog")
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .conflicts_with_subcommand("commit"),
+        )
+        .subcommand(App::new("commit"))
+        .try_get_matches_from(vec!["prog", "commit"]);
+
+    assert!(result.is_ok(), "{:?}", result.unwrap_err());
+}
+
+fn conflicts_with_group_app() -> App<'static> {
+    App::new("prog")
+        .arg(Arg::new("quiet").long("quiet").conflicts_with("output"))
+        .arg(Arg::new("json").long("json"))
+        .arg(Arg::new("yaml").long("yaml"))
+        .group(ArgGroup::new("output").args(&["json", "yaml"]))
+}
+
+#[test]
+fn conflicts_with_group_any_member_errs() {
+    for member in &["--json", "--yaml"] {
+        let res = conflicts_with_group_app().try_get_matches_from(vec!["prog", "--quiet", member]);
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().kind, ErrorKind::ArgumentConflict);
+    }
+}
+
+#[test]
+fn conflicts_with_group_no_member_ok() {
+    let res = conflicts_with_group_app().try_get_matches_from(vec!["prog", "--quiet"]);
+    assert!(res.is_ok(), "{:?}", res.unwrap_err());
+}
+
+#[test]
+fn conflicts_with_group_member_without_arg_ok() {
+    let res = conflicts_with_group_app().try_get_matches_from(vec!["prog", "--json"]);
+    assert!(res.is_ok(), "{:?}", res.unwrap_err());
+}
+
+#[test]
+fn arg_conflicts_resolves_names() {
+    let app = App::new("conflict")
+        .arg(Arg::from("-f, --flag 'some flag'").conflicts_with_all(&["other", "third"]))
+        .arg(Arg::from("-o, --other 'some other flag'"))
+        .arg(Arg::from("-t, --third 'a third flag'"));
+
+    assert_eq!(app.arg_conflicts("flag"), ["other", "third"]);
+    assert_eq!(app.arg_conflicts("other"), Vec::<&str>::new());
+}
+
+#[test]
+fn arg_conflicts_resolves_group_names() {
+    let app = App::new("conflict")
+        .arg(Arg::from("-f, --flag 'some flag'").conflicts_with("mode"))
+        .arg(Arg::from("-o, --other 'some other flag'"))
+        .group(ArgGroup::new("mode").arg("other"));
+
+    assert_eq!(app.arg_conflicts("flag"), ["mode"]);
+}
@@ -108,3 +108,111 @@ fn opt_default_user_override() {
     assert!(m.is_present("o"));
     assert_eq!(m.value_of("o").unwrap(), "value");
 }
+
+//## `default_missing_values` with multi-value options
+
+#[test]
+fn multi_opt_missing_values() {
+    // `--features` given without values applies the whole default_missing_values set
+    let r = App::new("df")
+        .arg(
+            Arg::new("features")
+                .long("features")
+                .min_values(0)
+                .multiple_values(true)
+                .default_missing_values(&["a", "b", "c"]),
+        )
+        .try_get_matches_from(vec!["", "--features"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("features"));
+    assert_eq!(
+        m.values_of("features").unwrap().collect::<Vec<_>>(),
+        ["a", "b", "c"]
+    );
+    assert_eq!(m.occurrences_of("features"), 1);
+}
+
+#[test]
+fn multi_opt_one_value() {
+    // an explicit value wins over the default_missing_values set
+    let r = App::new("df")
+        .arg(
+            Arg::new("features")
+                .long("features")
+                .min_values(0)
+                .multiple_values(true)
+                .default_missing_values(&["a", "b", "c"]),
+        )
+        .try_get_matches_from(vec!["", "--features", "x"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("features"));
+    assert_eq!(m.values_of("features").unwrap().collect::<Vec<_>>(), ["x"]);
+    assert_eq!(m.occurrences_of("features"), 1);
+}
+
+#[test]
+fn multi_opt_multiple_values() {
+    // space separated explicit values win over the default_missing_values set
+    let r = App::new("df")
+        .arg(
+            Arg::new("features")
+                .long("features")
+                .min_values(0)
+                .multiple_values(true)
+                .default_missing_values(&["a", "b", "c"]),
+        )
+        .try_get_matches_from(vec!["", "--features", "x", "y"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("features"));
+    assert_eq!(
+        m.values_of("features").unwrap().collect::<Vec<_>>(),
+        ["x", "y"]
+    );
+    assert_eq!(m.occurrences_of("features"), 1);
+}
+
+//## `require_equals` + `min_values(0)`: `--opt`, `--opt=val` and the space form
+
+fn optional_equals_app() -> App<'static> {
+    App::new("df")
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .min_values(0)
+                .require_equals(true)
+                .default_missing_value("always"),
+        )
+        .arg(Arg::new("file"))
+}
+
+#[test]
+fn require_equals_min_values_zero_no_value() {
+    let m = optional_equals_app()
+        .try_get_matches_from(vec!["", "--color"])
+        .unwrap();
+    assert_eq!(m.value_of("color"), Some("always"));
+    assert!(!m.is_present("file"));
+}
+
+#[test]
+fn require_equals_min_values_zero_equals_form() {
+    let m = optional_equals_app()
+        .try_get_matches_from(vec!["", "--color=never"])
+        .unwrap();
+    assert_eq!(m.value_of("color"), Some("never"));
+    assert!(!m.is_present("file"));
+}
+
+#[test]
+fn require_equals_min_values_zero_space_form_not_consumed() {
+    // the token after the space is never a value for the option; it matches as the next
+    // argument or positional instead
+    let m = optional_equals_app()
+        .try_get_matches_from(vec!["", "--color", "never"])
+        .unwrap();
+    assert_eq!(m.value_of("color"), Some("always"));
+    assert_eq!(m.value_of("file"), Some("never"));
+}
@@ -525,3 +525,114 @@ fn required_args_with_default_values() {
         .arg(Arg::new("arg").required(true).default_value("value"))
         .try_get_matches();
 }
+
+#[test]
+fn default_value_from_arg() {
+    let m = App::new("df")
+        .arg(Arg::new("project-dir").long("project-dir").takes_value(true))
+        .arg(
+            Arg::new("output-dir")
+                .long("output-dir")
+                .default_value_from_arg("project-dir"),
+        )
+        .get_matches_from(vec!["df", "--project-dir", "/work"]);
+
+    assert_eq!(m.value_of("output-dir"), Some("/work"));
+    assert_eq!(m.value_of("project-dir"), Some("/work"));
+}
+
+#[test]
+fn default_value_from_arg_source_defaulted() {
+    let m = App::new("df")
+        .arg(
+            Arg::new("project-dir")
+                .long("project-dir")
+                .default_value("."),
+        )
+        .arg(
+            Arg::new("output-dir")
+                .long("output-dir")
+                .default_value_from_arg("project-dir"),
+        )
+        .get_matches_from(vec!["df"]);
+
+    assert_eq!(m.value_of("output-dir"), Some("."));
+}
+
+#[test]
+fn default_value_from_arg_explicit_wins() {
+    let m = App::new("df")
+        .arg(Arg::new("project-dir").long("project-dir").takes_value(true))
+        .arg(
+            Arg::new("output-dir")
+                .long("output-dir")
+                .default_value_from_arg("project-dir"),
+        )
+        .get_matches_from(vec!["df", "--project-dir", "/work", "--output-dir", "/out"]);
+
+    assert_eq!(m.value_of("output-dir"), Some("/out"));
+}
+
+#[test]
+fn default_value_from_arg_source_absent_uses_own_default() {
+    let m = App::new("df")
+        .arg(Arg::new("project-dir").long("project-dir").takes_value(true))
+        .arg(
+            Arg::new("output-dir")
+                .long("output-dir")
+                .default_value("out")
+                .default_value_from_arg("project-dir"),
+        )
+        .get_matches_from(vec!["df"]);
+
+    assert_eq!(m.value_of("output-dir"), Some("out"));
+}
+
+#[test]
+fn default_value_from_count_two_occurrences() {
+    let r = App::new("df")
+        .arg(Arg::new("verbose").short('v').multiple_occurrences(true))
+        .arg(
+            Arg::new("threads")
+                .long("threads")
+                .takes_value(true)
+                .default_value_from_count("verbose"),
+        )
+        .try_get_matches_from(vec!["", "-v", "-v"]);
+    assert!(r.is_ok(), "{}", r.unwrap_err());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("threads"), Some("2"));
+    assert_eq!(m.value_source("threads"), Some(clap::ValueSource::DefaultValue));
+}
+
+#[test]
+fn default_value_from_count_absent_source() {
+    let r = App::new("df")
+        .arg(Arg::new("verbose").short('v').multiple_occurrences(true))
+        .arg(
+            Arg::new("threads")
+                .long("threads")
+                .takes_value(true)
+                .default_value_from_count("verbose"),
+        )
+        .try_get_matches_from(vec![""]);
+    assert!(r.is_ok(), "{}", r.unwrap_err());
+    assert_eq!(r.unwrap().value_of("threads"), Some("0"));
+}
+
+#[test]
+fn default_value_from_count_user_override() {
+    let r = App::new("df")
+        .arg(Arg::new("verbose").short('v').multiple_occurrences(true))
+        .arg(
+            Arg::new("threads")
+                .long("threads")
+                .takes_value(true)
+                .default_value_from_count("verbose"),
+        )
+        .try_get_matches_from(vec!["", "-v", "--threads", "8"]);
+    assert!(r.is_ok(), "{}", r.unwrap_err());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("threads"), Some("8"));
+    assert_eq!(m.value_source("threads"), Some(clap::ValueSource::CommandLine));
+}
@@ -131,3 +131,148 @@ fn opt_eq_mult_def_delim() {
         &["val1", "val2", "val3"]
     );
 }
+
+#[test]
+fn quoted_delimiter_not_split() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("tags")
+                .long("tags")
+                .use_delimiter(true)
+                .allow_quoted_delimiters(true),
+        )
+        .try_get_matches_from(vec!["prog", "--tags", "\"a,b\",c,'d,e'"])
+        .unwrap();
+    assert_eq!(
+        m.values_of("tags").unwrap().collect::<Vec<_>>(),
+        ["a,b", "c", "d,e"]
+    );
+}
+
+#[test]
+fn quoted_delimiter_inner_quotes_kept() {
+    // Only the outermost matching quotes are stripped
+    let m = App::new("prog")
+        .arg(
+            Arg::new("tags")
+                .long("tags")
+                .use_delimiter(true)
+                .allow_quoted_delimiters(true),
+        )
+        .try_get_matches_from(vec!["prog", "--tags", "\"a,'b',c\",d"])
+        .unwrap();
+    assert_eq!(
+        m.values_of("tags").unwrap().collect::<Vec<_>>(),
+        ["a,'b',c", "d"]
+    );
+}
+
+#[test]
+fn quoted_delimiter_unclosed_quote_literal() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("tags")
+                .long("tags")
+                .use_delimiter(true)
+                .allow_quoted_delimiters(true),
+        )
+        .try_get_matches_from(vec!["prog", "--tags", "a,\"b,c"])
+        .unwrap();
+    assert_eq!(
+        m.values_of("tags").unwrap().collect::<Vec<_>>(),
+        ["a", "\"b,c"]
+    );
+}
+
+#[test]
+fn opt_multi_char_delim() {
+    let m = App::new("delims")
+        .arg(
+            Arg::new("path")
+                .long("path")
+                .value_delimiter("::"),
+        )
+        .try_get_matches_from(vec!["", "--path", "a::b::c"]);
+
+    assert!(m.is_ok());
+    let m = m.unwrap();
+
+    assert!(m.is_present("path"));
+    assert_eq!(
+        m.values_of("path").unwrap().collect::<Vec<_>>(),
+        &["a", "b", "c"]
+    );
+}
+
+#[test]
+fn opt_multi_char_delim_not_split_on_single_char() {
+    let m = App::new("delims")
+        .arg(
+            Arg::new("path")
+                .long("path")
+                .value_delimiter("::"),
+        )
+        .try_get_matches_from(vec!["", "--path", "a:b::c"]);
+
+    assert!(m.is_ok());
+    let m = m.unwrap();
+
+    assert_eq!(
+        m.values_of("path").unwrap().collect::<Vec<_>>(),
+        &["a:b", "c"]
+    );
+}
+
+#[test]
+fn balanced_delimiters_keep_bracketed_commas() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("filter")
+                .long("filter")
+                .use_delimiter(true)
+                .balanced_delimiters(true),
+        )
+        .try_get_matches_from(vec!["prog", "--filter", "a,(b,c),d"])
+        .unwrap();
+
+    assert_eq!(
+        m.values_of("filter").unwrap().collect::<Vec<_>>(),
+        ["a", "(b,c)", "d"]
+    );
+}
+
+#[test]
+fn balanced_delimiters_track_all_bracket_kinds() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("filter")
+                .long("filter")
+                .use_delimiter(true)
+                .balanced_delimiters(true),
+        )
+        .try_get_matches_from(vec!["prog", "--filter", "[a,b],{c,(d,e)},f"])
+        .unwrap();
+
+    assert_eq!(
+        m.values_of("filter").unwrap().collect::<Vec<_>>(),
+        ["[a,b]", "{c,(d,e)}", "f"]
+    );
+}
+
+#[test]
+fn balanced_delimiters_unclosed_bracket_stops_splitting() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("filter")
+                .long("filter")
+                .use_delimiter(true)
+                .balanced_delimiters(true),
+        )
+        .try_get_matches_from(vec!["prog", "--filter", "a,(b,c"])
+        .unwrap();
+
+    assert_eq!(
+        m.values_of("filter").unwrap().collect::<Vec<_>>(),
+        ["a", "(b,c"]
+    );
+}
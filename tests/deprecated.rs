@@ -0,0 +1,46 @@
+use clap::{App, Arg};
+
+#[test]
+fn deprecated_arg_still_matches_and_stores_values() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("color")
+                .long("colour")
+                .takes_value(true)
+                .deprecated("'--colour' is deprecated, use '--color' instead"),
+        )
+        .try_get_matches_from(vec!["prog", "--colour", "red"]);
+
+    assert!(m.is_ok());
+    let m = m.unwrap();
+    assert!(m.is_present("color"));
+    assert_eq!(m.value_of("color"), Some("red"));
+}
+
+#[test]
+fn deprecated_arg_unused_parses_normally() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("color")
+                .long("colour")
+                .deprecated("'--colour' is deprecated, use '--color' instead"),
+        )
+        .arg(Arg::new("verbose").long("verbose"))
+        .try_get_matches_from(vec!["prog", "--verbose"]);
+
+    assert!(m.is_ok());
+    let m = m.unwrap();
+    assert!(!m.is_present("color"));
+    assert!(m.is_present("verbose"));
+}
+
+#[test]
+fn get_deprecated() {
+    let arg = Arg::new("color")
+        .long("colour")
+        .deprecated("use '--color' instead");
+    assert_eq!(arg.get_deprecated(), Some("use '--color' instead"));
+
+    let arg = Arg::new("color").long("color");
+    assert_eq!(arg.get_deprecated(), None);
+}
@@ -396,3 +396,76 @@ fn prefer_user_help_in_subcommand_with_derive_order() {
         false
     ));
 }
+
+static DISPLAY_BEFORE_AFTER: &str = "test 1.2
+
+USAGE:
+    test [FLAGS]
+
+FLAGS:
+        --flag_a     first flag
+        --flag_b     inserted flag
+        --flag_c     third flag
+    -h, --help       Prints help information
+    -V, --version    Prints version information";
+
+#[test]
+fn display_before_after_inserts_between() {
+    let app = App::new("test")
+        .version("1.2")
+        .arg(
+            Arg::new("flag_a")
+                .long("flag_a")
+                .display_order(1)
+                .about("first flag"),
+        )
+        .arg(
+            Arg::new("flag_c")
+                .long("flag_c")
+                .display_order(2)
+                .about("third flag"),
+        )
+        .arg(
+            Arg::new("flag_b")
+                .long("flag_b")
+                .display_after("flag_a")
+                .display_before("flag_c")
+                .about("inserted flag"),
+        );
+
+    assert!(utils::compare_output(
+        app,
+        "test --help",
+        DISPLAY_BEFORE_AFTER,
+        false
+    ));
+}
+
+// This tests a programmer error and will only succeed with debug_assertions
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "does not exist"]
+fn display_before_unknown_arg_panics() {
+    let mut app = App::new("test").arg(Arg::new("flag_a").long("flag_a").display_before("nope"));
+    app._build();
+}
+
+// This tests a programmer error and will only succeed with debug_assertions
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "positional targets are not supported"]
+fn display_before_positional_arg_panics() {
+    let mut app = App::new("test")
+        .arg(Arg::new("pos"))
+        .arg(Arg::new("flag_a").long("flag_a").display_before("pos"));
+    app._build();
+}
+
+#[test]
+#[should_panic = "Cycle detected"]
+fn display_order_cycle_panics() {
+    let mut app = App::new("test")
+        .arg(Arg::new("flag_a").long("flag_a").display_before("flag_b"))
+        .arg(Arg::new("flag_b").long("flag_b").display_before("flag_a"));
+    app._build();
+}
@@ -1,7 +1,7 @@
 use std::env;
 use std::ffi::OsStr;
 
-use clap::{App, Arg};
+use clap::{App, Arg, ValueSource};
 
 #[test]
 fn env() {
@@ -346,3 +346,224 @@ fn validator_invalid() {
 
     assert!(r.is_err());
 }
+
+#[test]
+fn env_or_uses_env_when_set() {
+    env::set_var("CLP_TEST_ENV_OR_SET", "env");
+
+    let m = App::new("df")
+        .arg(Arg::new("arg").long("arg").env_or("CLP_TEST_ENV_OR_SET", "fallback"))
+        .try_get_matches_from(vec![""])
+        .unwrap();
+
+    assert!(m.is_present("arg"));
+    assert_eq!(m.value_of("arg"), Some("env"));
+}
+
+#[test]
+fn env_or_falls_back_when_unset() {
+    let m = App::new("df")
+        .arg(Arg::new("arg").long("arg").env_or("CLP_TEST_ENV_OR_UNSET", "fallback"))
+        .try_get_matches_from(vec![""])
+        .unwrap();
+
+    assert!(m.is_present("arg"));
+    assert_eq!(m.value_of("arg"), Some("fallback"));
+}
+
+#[test]
+fn env_or_cli_wins() {
+    env::set_var("CLP_TEST_ENV_OR_CLI", "env");
+
+    let m = App::new("df")
+        .arg(Arg::new("arg").long("arg").env_or("CLP_TEST_ENV_OR_CLI", "fallback"))
+        .try_get_matches_from(vec!["", "--arg", "cli"])
+        .unwrap();
+
+    assert_eq!(m.value_of("arg"), Some("cli"));
+}
+
+#[test]
+fn value_source_env() {
+    env::set_var("CLP_TEST_ENV_SOURCE", "env");
+
+    let m = App::new("df")
+        .arg(
+            Arg::new("arg")
+                .long("arg")
+                .env("CLP_TEST_ENV_SOURCE")
+                .takes_value(true),
+        )
+        .try_get_matches_from(vec![""])
+        .unwrap();
+
+    assert_eq!(m.value_source("arg"), Some(ValueSource::EnvVariable));
+}
+
+#[test]
+fn value_source_cli_beats_env() {
+    env::set_var("CLP_TEST_ENV_SOURCE_CLI", "env");
+
+    let m = App::new("df")
+        .arg(
+            Arg::new("arg")
+                .long("arg")
+                .env("CLP_TEST_ENV_SOURCE_CLI")
+                .takes_value(true),
+        )
+        .try_get_matches_from(vec!["", "--arg", "cli"])
+        .unwrap();
+
+    assert_eq!(m.value_source("arg"), Some(ValueSource::CommandLine));
+}
+
+#[test]
+fn value_source_default() {
+    let m = App::new("df")
+        .arg(
+            Arg::new("arg")
+                .long("arg")
+                .takes_value(true)
+                .default_value("dflt"),
+        )
+        .try_get_matches_from(vec![""])
+        .unwrap();
+
+    assert_eq!(m.value_source("arg"), Some(ValueSource::DefaultValue));
+}
+
+#[test]
+fn value_source_absent() {
+    let m = App::new("df")
+        .arg(Arg::new("arg").long("arg").takes_value(true))
+        .try_get_matches_from(vec![""])
+        .unwrap();
+
+    assert_eq!(m.value_source("arg"), None);
+}
+
+#[test]
+fn env_var_set_after_building_arg() {
+    let app = App::new("df").arg(
+        Arg::new("arg")
+            .long("arg")
+            .takes_value(true)
+            .env("CLP_TEST_ENV_LATE"),
+    );
+    // The variable only appears after the arg captured it; parsing must see the new value
+    env::set_var("CLP_TEST_ENV_LATE", "late");
+
+    let r = app.try_get_matches_from(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("arg"), Some("late"));
+}
+
+#[test]
+fn env_satisfies_required() {
+    env::set_var("CLP_TEST_ENV_REQUIRED_SET", "from-env");
+
+    let r = App::new("df")
+        .arg(
+            Arg::new("arg")
+                .long("arg")
+                .required(true)
+                .takes_value(true)
+                .env("CLP_TEST_ENV_REQUIRED_SET"),
+        )
+        .try_get_matches_from(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("arg"), Some("from-env"));
+    assert_eq!(m.value_source("arg"), Some(ValueSource::EnvVariable));
+}
+
+#[test]
+fn env_missing_fails_required() {
+    env::remove_var("CLP_TEST_ENV_REQUIRED_UNSET");
+
+    let r = App::new("df")
+        .arg(
+            Arg::new("arg")
+                .long("arg")
+                .required(true)
+                .takes_value(true)
+                .env("CLP_TEST_ENV_REQUIRED_UNSET"),
+        )
+        .try_get_matches_from(vec![""]);
+
+    assert!(r.is_err());
+    assert_eq!(
+        r.unwrap_err().kind,
+        clap::ErrorKind::MissingRequiredArgument
+    );
+}
+
+#[test]
+fn env_line_delimited_splits_on_newlines() {
+    env::set_var("CLP_TEST_ENV_LINES", "env1\nenv2\nenv3");
+
+    let r = App::new("df")
+        .arg(
+            Arg::from("[arg] 'some opt'")
+                .env("CLP_TEST_ENV_LINES")
+                .takes_value(true)
+                .multiple(true)
+                .env_line_delimited(true),
+        )
+        .try_get_matches_from(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(
+        m.values_of("arg").unwrap().collect::<Vec<_>>(),
+        vec!["env1", "env2", "env3"]
+    );
+}
+
+#[test]
+fn env_line_delimited_strips_crlf_and_empty_lines() {
+    env::set_var("CLP_TEST_ENV_LINES_CRLF", "env1\r\nenv2\r\n\r\nenv3\r\n");
+
+    let r = App::new("df")
+        .arg(
+            Arg::from("[arg] 'some opt'")
+                .env("CLP_TEST_ENV_LINES_CRLF")
+                .takes_value(true)
+                .multiple(true)
+                .env_line_delimited(true),
+        )
+        .try_get_matches_from(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(
+        m.values_of("arg").unwrap().collect::<Vec<_>>(),
+        vec!["env1", "env2", "env3"]
+    );
+}
+
+#[test]
+fn env_line_delimited_cli_values_keep_delimiter() {
+    env::set_var("CLP_TEST_ENV_LINES_CLI", "env1\nenv2");
+
+    let r = App::new("df")
+        .arg(
+            Arg::from("[arg] 'some opt'")
+                .env("CLP_TEST_ENV_LINES_CLI")
+                .takes_value(true)
+                .use_delimiter(true)
+                .multiple(true)
+                .env_line_delimited(true),
+        )
+        .try_get_matches_from(vec!["", "cli1,cli2"]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(
+        m.values_of("arg").unwrap().collect::<Vec<_>>(),
+        vec!["cli1", "cli2"]
+    );
+}
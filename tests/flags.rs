@@ -1,5 +1,5 @@
 mod utils;
-use clap::{App, Arg};
+use clap::{App, AppSettings, Arg};
 
 const USE_FLAG_AS_ARGUMENT: &str =
     "error: Found argument '--another-flag' which wasn't expected, or isn't valid in this context
@@ -167,3 +167,85 @@ For more information try --help";
         true
     ));
 }
+
+#[test]
+fn digit_short_flag() {
+    let m = App::new("test")
+        .arg(Arg::new("one").short('1'))
+        .get_matches_from(vec!["test", "-1"]);
+    assert!(m.is_present("one"));
+}
+
+#[test]
+fn digit_short_flag_precedence() {
+    // Without AllowNegativeNumbers, `-1` is the digit short flag
+    let m = App::new("test")
+        .arg(Arg::new("one").short('1'))
+        .arg(Arg::new("num"))
+        .get_matches_from(vec!["test", "-1"]);
+    assert!(m.is_present("one"));
+    assert_eq!(m.value_of("num"), None);
+
+    // With AllowNegativeNumbers, anything that parses as a number is preferred
+    // as a value for a value-taking arg over the digit short flag
+    let m = App::new("test")
+        .setting(AppSettings::AllowNegativeNumbers)
+        .arg(Arg::new("one").short('1'))
+        .arg(Arg::new("num"))
+        .get_matches_from(vec!["test", "-1"]);
+    assert!(!m.is_present("one"));
+    assert_eq!(m.value_of("num"), Some("-1"));
+}
+
+#[test]
+fn long_short_sets_both() {
+    let m = App::new("prog")
+        .arg(Arg::new("cfg").long_short("--config"))
+        .try_get_matches_from(vec!["prog", "--config"])
+        .unwrap();
+    assert!(m.is_present("cfg"));
+
+    let m = App::new("prog")
+        .arg(Arg::new("cfg").long_short("--config"))
+        .try_get_matches_from(vec!["prog", "-c"])
+        .unwrap();
+    assert!(m.is_present("cfg"));
+}
+
+#[test]
+fn final_takes_value_false_overrides_implications() {
+    // `possible_values` implies `TakesValue` when called, so a later explicit
+    // `takes_value(false)` must win and keep the arg a flag.
+    let m = App::new("prog")
+        .arg(
+            Arg::new("mode")
+                .long("mode")
+                .possible_values(&["fast", "slow"])
+                .takes_value(false),
+        )
+        .try_get_matches_from(vec!["prog", "--mode"])
+        .unwrap();
+    assert!(m.is_present("mode"));
+    assert_eq!(m.value_of("mode"), None);
+
+    let res = App::new("prog")
+        .arg(
+            Arg::new("mode")
+                .long("mode")
+                .possible_values(&["fast", "slow"])
+                .takes_value(false),
+        )
+        .try_get_matches_from(vec!["prog", "--mode", "fast"]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn flag_with_owned_long() {
+    let long = String::from("--runtime-flag");
+    let m = App::new("test")
+        .arg(Arg::new("flag").long(long))
+        .try_get_matches_from(vec!["test", "--runtime-flag"])
+        .unwrap();
+
+    assert!(m.is_present("flag"));
+}
@@ -206,3 +206,17 @@ fn issue_2171() {
         let _ = schema.clone().try_get_matches_from(argv).unwrap();
     }
 }
+
+#[test]
+fn grouped_value_fixed_width_tuples() {
+    let m = App::new("cli")
+        .arg(
+            Arg::new("point")
+                .long("point")
+                .number_of_values(2)
+                .multiple_occurrences(true),
+        )
+        .get_matches_from(&["cli", "--point", "1", "2", "--point", "3", "4"]);
+    let points: Vec<Vec<&str>> = m.grouped_values_of("point").unwrap().collect();
+    assert_eq!(points, vec![vec!["1", "2"], vec!["3", "4"]]);
+}
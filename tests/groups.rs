@@ -11,7 +11,7 @@ USAGE:
 For more information try --help";
 
 static REQ_GROUP_CONFLICT_USAGE: &str =
-    "error: The argument '--delete' cannot be used with '<base>'
+    "error: The argument '<base>' cannot be used with '--delete'
 
 USAGE:
     clap-test <base|--delete>
@@ -19,7 +19,7 @@ USAGE:
 For more information try --help";
 
 static REQ_GROUP_CONFLICT_ONLY_OPTIONS: &str =
-    "error: The argument '--delete' cannot be used with '--all'
+    "error: The argument '--all' cannot be used with '--delete'
 
 USAGE:
     clap-test <--all|--delete>
@@ -312,3 +312,52 @@ fn issue_1794() {
     assert!(m.is_present("option1"));
 }
 */
+
+#[test]
+fn get_arg_groups_reports_membership_from_both_sides() {
+    let mut app = App::new("prog")
+        .arg(Arg::new("flag").long("flag").group("verb"))
+        .arg(Arg::new("color").long("color"))
+        .group(ArgGroup::new("verb"))
+        .group(ArgGroup::new("format").arg("color"));
+    app._build();
+
+    let flag = app.get_arguments().find(|a| a.get_name() == "flag").unwrap();
+    let groups: Vec<_> = app.get_arg_groups(flag).iter().map(|g| g.get_name()).collect();
+    assert_eq!(groups, ["verb"]);
+
+    let color = app
+        .get_arguments()
+        .find(|a| a.get_name() == "color")
+        .unwrap();
+    let groups: Vec<_> = app
+        .get_arg_groups(color)
+        .iter()
+        .map(|g| g.get_name())
+        .collect();
+    assert_eq!(groups, ["format"]);
+}
+
+#[test]
+fn groups_for_arg_names() {
+    let app = App::new("prog")
+        .arg(Arg::new("json").long("json"))
+        .arg(Arg::new("yaml").long("yaml").group("verbose-formats"))
+        .group(ArgGroup::new("format").args(&["json", "yaml"]))
+        .group(ArgGroup::new("verbose-formats"));
+
+    assert_eq!(app.groups_for_arg("json"), ["format"]);
+    assert_eq!(app.groups_for_arg("yaml"), ["format", "verbose-formats"]);
+}
+
+#[test]
+fn args_in_group_names() {
+    let app = App::new("prog")
+        .arg(Arg::new("json").long("json"))
+        .arg(Arg::new("yaml").long("yaml").group("format"))
+        .arg(Arg::new("quiet").long("quiet"))
+        .group(ArgGroup::new("format").arg("json"));
+
+    assert_eq!(app.args_in_group("format"), ["json", "yaml"]);
+    assert_eq!(app.groups_for_arg("quiet"), Vec::<&str>::new());
+}
@@ -269,6 +269,73 @@ OPTIONS:
     -c, --cafe <FILE>    A coffeehouse, coffee shop, or café.
     -p, --pos <VAL>      Some vals [possible values: fast, slow]";
 
+static SHOW_PV_ALIASES: &str = "ctest 0.1
+
+USAGE:
+    ctest [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+    -m, --mode <MODE>    Some vals [possible values: fast (aliases: f, quick), slow]";
+
+static HIDE_DEFAULT_IF_SHOWN: &str = "ctest 0.1
+
+USAGE:
+    ctest [FLAGS] [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -v, --verbose    Lots of output
+    -V, --version    Prints version information
+
+OPTIONS:
+    -c, --config <config>    The config [default: config.toml]";
+
+static HIDE_DEFAULT_IF_HIDDEN: &str = "ctest 0.1
+
+USAGE:
+    ctest [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+    -c, --config <config>    The config";
+
+static VAL_NAME_CASE_BY_KIND: &str = "ctest 0.1
+
+USAGE:
+    ctest [OPTIONS] [infile]
+
+ARGS:
+    <infile>    The input
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+    -o, --out <FILE>    The output";
+
+static VAL_NAME_CASE_UNCHANGED: &str = "ctest 0.1
+
+USAGE:
+    ctest [OPTIONS] [INFILE]
+
+ARGS:
+    <INFILE>    The input
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+    -o, --out <file>    The output";
+
 static FINAL_WORD_WRAPPING: &str = "ctest 0.1
 
 USAGE:
@@ -1067,6 +1134,127 @@ fn hide_possible_vals() {
     ));
 }
 
+#[test]
+fn hide_default_value_if_target_visible() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .arg(
+            Arg::new("config")
+                .short('c')
+                .long("config")
+                .takes_value(true)
+                .default_value("config.toml")
+                .hide_default_value_if("verbose")
+                .about("The config"),
+        )
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .long("verbose")
+                .about("Lots of output"),
+        );
+    assert!(utils::compare_output(
+        app,
+        "ctest --help",
+        HIDE_DEFAULT_IF_SHOWN,
+        false
+    ));
+}
+
+#[test]
+fn hide_default_value_if_target_hidden() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .arg(
+            Arg::new("config")
+                .short('c')
+                .long("config")
+                .takes_value(true)
+                .default_value("config.toml")
+                .hide_default_value_if("verbose")
+                .about("The config"),
+        )
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .long("verbose")
+                .hidden(true)
+                .about("Lots of output"),
+        );
+    assert!(utils::compare_output(
+        app,
+        "ctest --help",
+        HIDE_DEFAULT_IF_HIDDEN,
+        false
+    ));
+}
+
+#[test]
+fn value_name_case_by_kind() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .arg(
+            Arg::new("out")
+                .short('o')
+                .long("out")
+                .value_name("file")
+                .value_name_case_by_kind(true)
+                .about("The output"),
+        )
+        .arg(
+            Arg::new("infile")
+                .value_name("INFILE")
+                .value_name_case_by_kind(true)
+                .about("The input"),
+        );
+    assert!(utils::compare_output(
+        app,
+        "ctest --help",
+        VAL_NAME_CASE_BY_KIND,
+        false
+    ));
+}
+
+#[test]
+fn value_name_case_unchanged_without_setting() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .arg(
+            Arg::new("out")
+                .short('o')
+                .long("out")
+                .value_name("file")
+                .about("The output"),
+        )
+        .arg(Arg::new("infile").value_name("INFILE").about("The input"));
+    assert!(utils::compare_output(
+        app,
+        "ctest --help",
+        VAL_NAME_CASE_UNCHANGED,
+        false
+    ));
+}
+
+#[test]
+fn show_possible_value_aliases() {
+    let app = App::new("ctest").version("0.1").arg(
+        Arg::new("mode")
+            .short('m')
+            .long("mode")
+            .value_name("MODE")
+            .possible_value_aliased("fast", &["f", "quick"])
+            .possible_value("slow")
+            .show_possible_value_aliases(true)
+            .about("Some vals"),
+    );
+    assert!(utils::compare_output(
+        app,
+        "ctest --help",
+        SHOW_PV_ALIASES,
+        false
+    ));
+}
+
 #[test]
 fn issue_626_panic() {
     let app = App::new("ctest")
@@ -1929,6 +2117,73 @@ fn multiple_custom_help_headers() {
     ));
 }
 
+static ORDERED_CUSTOM_HELP_SECTIONS: &str = "blorp 1.4
+
+does stuff
+
+USAGE:
+    test
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+NETWORKING:
+    -n, --no-proxy    Do not use system proxy settings
+
+LOGGING:
+    -l, --log-file <FILE>    Write logs to FILE
+    -v, --verbose            Use verbose output
+
+MISC:
+    -c, --color    Colorize output";
+
+#[test]
+fn ordered_custom_help_headers() {
+    let app = App::new("blorp")
+        .about("does stuff")
+        .version("1.4")
+        .arg(
+            Arg::new("log-file")
+                .short('l')
+                .long("log-file")
+                .value_name("FILE")
+                .takes_value(true)
+                .about("Write logs to FILE")
+                .help_heading(Some("LOGGING"))
+                .help_heading_order(2),
+        )
+        .arg(
+            Arg::new("no-proxy")
+                .short('n')
+                .long("no-proxy")
+                .about("Do not use system proxy settings")
+                .help_heading(Some("NETWORKING"))
+                .help_heading_order(1),
+        )
+        .arg(
+            Arg::new("color")
+                .short('c')
+                .long("color")
+                .about("Colorize output")
+                .help_heading(Some("MISC")),
+        )
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .long("verbose")
+                .about("Use verbose output")
+                .help_heading(Some("LOGGING")),
+        );
+
+    assert!(utils::compare_output(
+        app,
+        "test --help",
+        ORDERED_CUSTOM_HELP_SECTIONS,
+        false
+    ));
+}
+
 static ISSUE_897: &str = "ctest-foo 0.1
 
 Long about foo
@@ -2364,6 +2619,46 @@ fn custom_heading_pos() {
     ));
 }
 
+static UPPERCASE_CUSTOM_HEADING: &'static str = "test 1.4
+
+USAGE:
+    test
+
+FLAGS:
+    -h, --help    Prints help information
+
+NETWORK:
+        --port     Set port
+        --proxy    Use proxy";
+
+#[test]
+fn uppercase_custom_heading_coalesces_mixed_case() {
+    let app = App::new("test")
+        .version("1.4")
+        .setting(AppSettings::DisableVersionFlag)
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .about("Set port")
+                .help_heading(Some("NETWORK"))
+                .uppercase_help_heading(true),
+        )
+        .arg(
+            Arg::new("proxy")
+                .long("proxy")
+                .about("Use proxy")
+                .help_heading(Some("network"))
+                .uppercase_help_heading(true),
+        );
+
+    assert!(utils::compare_output(
+        app,
+        "test --help",
+        UPPERCASE_CUSTOM_HEADING,
+        false
+    ));
+}
+
 static ONLY_CUSTOM_HEADING_POS: &'static str = "test 1.4
 
 USAGE:
@@ -2465,3 +2760,246 @@ fn only_custom_heading_pos_no_args() {
         false
     ));
 }
+
+static POSITIONAL_BRACKETED_HEADER: &str = "test 1.4
+
+USAGE:
+    test [speed]
+
+ARGS:
+    <speed>    How fast
+
+FLAGS:
+    -h, --help    Prints help information";
+
+#[test]
+fn positional_help_header_is_bracketed() {
+    // Positionals render as `<name>` in the ARGS section, matching the form
+    // used in the usage string
+    let app = App::new("test")
+        .version("1.4")
+        .setting(AppSettings::DisableVersionFlag)
+        .arg(Arg::new("speed").about("How fast"));
+
+    assert!(utils::compare_output(
+        app,
+        "test --help",
+        POSITIONAL_BRACKETED_HEADER,
+        false
+    ));
+}
+
+static INTERPOLATE_BIN_ABOUT: &str = "prog 
+
+USAGE:
+    prog [FLAGS]
+
+FLAGS:
+        --config     Where prog looks for its configuration
+    -h, --help       Prints help information
+        --literal    A literal {bin} token
+    -V, --version    Prints version information";
+
+#[test]
+fn interpolate_bin_in_arg_about() {
+    let app = App::new("prog")
+        .arg(
+            Arg::new("cfg")
+                .long("config")
+                .about("Where {bin} looks for its configuration")
+                .interpolate_help(true),
+        )
+        .arg(
+            Arg::new("lit")
+                .long("literal")
+                .about("A literal {{bin}} token")
+                .interpolate_help(true),
+        );
+    assert!(utils::compare_output(
+        app,
+        "prog --help",
+        INTERPOLATE_BIN_ABOUT,
+        false
+    ));
+}
+
+static VALUE_NAME_AT_ORDERING: &str = "prog 
+
+USAGE:
+    prog [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+        --io <SRC> <MID> <DST>    ";
+
+#[test]
+fn value_name_at_out_of_order() {
+    let app = App::new("prog").arg(
+        Arg::new("io")
+            .long("io")
+            .value_name_at(2, "DST")
+            .value_name_at(0, "SRC")
+            .value_name_at(1, "MID"),
+    );
+    assert!(utils::compare_output(
+        app,
+        "prog --help",
+        VALUE_NAME_AT_ORDERING,
+        false
+    ));
+}
+
+#[test]
+fn help_heading_explicit_vs_inherited() {
+    let app = App::new("prog")
+        .help_heading("NETWORKING")
+        .arg(Arg::new("port").long("port"))
+        .stop_custom_headings()
+        .arg(Arg::new("speed").long("speed").help_heading(Some("SPEED")));
+
+    let port = app
+        .get_arguments()
+        .find(|a| a.get_name() == "port")
+        .unwrap();
+    assert_eq!(port.get_help_heading(), None);
+    assert_eq!(port.get_effective_help_heading(None), Some("NETWORKING"));
+
+    let speed = app
+        .get_arguments()
+        .find(|a| a.get_name() == "speed")
+        .unwrap();
+    assert_eq!(speed.get_help_heading(), Some("SPEED"));
+    assert_eq!(speed.get_effective_help_heading(None), Some("SPEED"));
+
+    let lone = Arg::new("quiet").long("quiet");
+    assert_eq!(lone.get_help_heading(), None);
+    assert_eq!(lone.get_effective_help_heading(Some("FLAGS")), Some("FLAGS"));
+}
+
+#[test]
+fn help_heading_owned_string() {
+    let plugin = String::from("git");
+    let app = App::new("prog").arg(
+        Arg::new("exec")
+            .long("exec")
+            .help_heading(Some(format!("OPTIONS FOR {}", plugin.to_uppercase()))),
+    );
+
+    let arg = app
+        .get_arguments()
+        .find(|a| a.get_name() == "exec")
+        .unwrap();
+    assert_eq!(arg.get_help_heading(), Some("OPTIONS FOR GIT"));
+}
+
+static AUTO_UPPERCASE_VAL_NAME: &str = "ctest 0.1
+
+USAGE:
+    ctest [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+    -o, --out <FILE>       The output
+    -ü, --über <GRÖSSE>    The size";
+
+#[test]
+fn auto_uppercase_value_name() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .arg(
+            Arg::new("out")
+                .short('o')
+                .long("out")
+                .value_name("file")
+                .auto_uppercase_value_name(true)
+                .about("The output"),
+        )
+        .arg(
+            Arg::new("über")
+                .short('ü')
+                .long("über")
+                .value_name("größe")
+                .auto_uppercase_value_name(true)
+                .about("The size"),
+        );
+    assert!(utils::compare_output(
+        app,
+        "ctest --help",
+        AUTO_UPPERCASE_VAL_NAME,
+        false
+    ));
+}
+
+static VALUE_UNIT: &str = "test 1.4
+
+USAGE:
+    test [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+    -t, --timeout <N> (seconds)    How long to wait";
+
+#[test]
+fn value_unit_rendered_after_value_name() {
+    let app = App::new("test").version("1.4").arg(
+        Arg::new("timeout")
+            .short('t')
+            .long("timeout")
+            .value_name("N")
+            .value_unit("seconds")
+            .about("How long to wait"),
+    );
+    assert!(utils::compare_output(app, "test --help", VALUE_UNIT, false));
+}
+
+static RANGED_VAL_NAME: &str = "test 2.1
+
+USAGE:
+    test [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+    -p, --port <PORT:1-65535>    The port to listen on";
+
+#[test]
+fn ranged_value_name_rendered_in_help() {
+    let app = App::new("test").version("2.1").arg(
+        Arg::new("port")
+            .short('p')
+            .long("port")
+            .value_name_ranged("PORT", 1, 65535)
+            .about("The port to listen on"),
+    );
+    assert!(utils::compare_output(app, "test --help", RANGED_VAL_NAME, false));
+}
+
+static OWNED_ABOUT: &str = "test 3.0
+
+USAGE:
+    test [FLAGS]
+
+FLAGS:
+    -f, --force      Overwrite the target of test
+    -h, --help       Prints help information
+    -V, --version    Prints version information";
+
+#[test]
+fn arg_about_with_owned_string() {
+    let about = format!("Overwrite the target of {}", "test");
+    let app = App::new("test")
+        .version("3.0")
+        .arg(Arg::new("force").short('f').long("force").about(about));
+    assert!(utils::compare_output(app, "test --help", OWNED_ABOUT, false));
+}
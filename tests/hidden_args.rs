@@ -412,3 +412,161 @@ fn hidden_subcmds_only() {
         false
     ));
 }
+
+static HIDDEN_UNLESS_ENV_UNSET: &str = "test 1.4
+
+tests stuff
+
+USAGE:
+    test [FLAGS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -s, --stable     a stable flag
+    -V, --version    Prints version information";
+
+static HIDDEN_UNLESS_ENV_SET: &str = "test 1.4
+
+tests stuff
+
+USAGE:
+    test [FLAGS]
+
+FLAGS:
+    -e, --experimental    an experimental flag
+    -h, --help            Prints help information
+    -s, --stable          a stable flag
+    -V, --version         Prints version information";
+
+fn hidden_unless_env_app() -> App<'static> {
+    App::new("test")
+        .about("tests stuff")
+        .version("1.4")
+        .arg(Arg::from("-s, --stable 'a stable flag'"))
+        .arg(
+            Arg::from("-e, --experimental 'an experimental flag'")
+                .hidden_unless_env("CLP_TEST_SHOW_HIDDEN"),
+        )
+}
+
+#[test]
+fn hidden_unless_env_var_unset() {
+    std::env::remove_var("CLP_TEST_SHOW_HIDDEN");
+    assert!(utils::compare_output(
+        hidden_unless_env_app(),
+        "test --help",
+        HIDDEN_UNLESS_ENV_UNSET,
+        false
+    ));
+}
+
+#[test]
+fn hidden_unless_env_var_set() {
+    std::env::set_var("CLP_TEST_SHOW_HIDDEN_SET", "1");
+    let app = App::new("test")
+        .about("tests stuff")
+        .version("1.4")
+        .arg(Arg::from("-s, --stable 'a stable flag'"))
+        .arg(
+            Arg::from("-e, --experimental 'an experimental flag'")
+                .hidden_unless_env("CLP_TEST_SHOW_HIDDEN_SET"),
+        );
+    assert!(utils::compare_output(
+        app,
+        "test --help",
+        HIDDEN_UNLESS_ENV_SET,
+        false
+    ));
+}
+
+#[test]
+fn hidden_unless_env_still_parses() {
+    std::env::remove_var("CLP_TEST_SHOW_HIDDEN_PARSE");
+    let m = App::new("test")
+        .arg(
+            Arg::new("experimental")
+                .long("experimental")
+                .hidden_unless_env("CLP_TEST_SHOW_HIDDEN_PARSE"),
+        )
+        .try_get_matches_from(vec!["test", "--experimental"])
+        .unwrap();
+
+    assert!(m.is_present("experimental"));
+}
+
+static HIDE_UNLESS_LONG_HELP_OFF: &str = "test 1.4
+
+tests stuff
+
+USAGE:
+    test [FLAGS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -s, --stable     a stable flag
+    -V, --version    Prints version information";
+
+static HIDE_UNLESS_LONG_HELP_ON: &str = "test 1.4
+
+tests stuff
+
+USAGE:
+    test [FLAGS]
+
+FLAGS:
+    -d, --dump-state
+            internal diagnostics
+
+    -h, --help
+            Prints help information
+
+    -s, --stable
+            a stable flag
+
+    -V, --version
+            Prints version information";
+
+fn hide_unless_long_help_app(show_hidden: bool) -> App<'static> {
+    let app = App::new("test")
+        .about("tests stuff")
+        .version("1.4")
+        .arg(Arg::from("-s, --stable 'a stable flag'"))
+        .arg(
+            Arg::from("-d, --dump-state 'internal diagnostics'").hide_unless_long_help(true),
+        );
+    if show_hidden {
+        app.setting(AppSettings::ShowHiddenArgs)
+    } else {
+        app
+    }
+}
+
+#[test]
+fn hide_unless_long_help_without_setting() {
+    assert!(utils::compare_output(
+        hide_unless_long_help_app(false),
+        "test --help",
+        HIDE_UNLESS_LONG_HELP_OFF,
+        false
+    ));
+}
+
+#[test]
+fn hide_unless_long_help_short_help_stays_hidden() {
+    assert!(utils::compare_output(
+        hide_unless_long_help_app(true),
+        "test -h",
+        HIDE_UNLESS_LONG_HELP_OFF,
+        false
+    ));
+}
+
+#[test]
+fn hide_unless_long_help_with_setting() {
+    assert!(utils::compare_output(
+        hide_unless_long_help_app(true),
+        "test --help",
+        HIDE_UNLESS_LONG_HELP_ON,
+        false
+    ));
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "json")]
+
+use clap::{App, Arg, ErrorKind};
+
+fn filter_app() -> App<'static> {
+    App::new("prog").arg(
+        Arg::new("filter")
+            .long("filter")
+            .takes_value(true)
+            .validate_json(),
+    )
+}
+
+fn schema_app() -> App<'static> {
+    App::new("prog").arg(
+        Arg::new("filter")
+            .long("filter")
+            .takes_value(true)
+            .validate_json_schema(
+                r#"{"type": "object", "required": ["k"], "properties": {"k": {"type": "integer"}}}"#,
+            ),
+    )
+}
+
+#[test]
+fn valid_json() {
+    let res = filter_app().try_get_matches_from(vec!["prog", "--filter", r#"{"k":1}"#]);
+    assert!(res.is_ok());
+    assert_eq!(res.unwrap().value_of("filter"), Some(r#"{"k":1}"#));
+}
+
+#[test]
+fn malformed_json() {
+    let res = filter_app().try_get_matches_from(vec!["prog", "--filter", r#"{"k":1"#]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    // serde_json errors carry the position of the offending token
+    assert!(err.to_string().contains("column"), "{}", err);
+}
+
+#[test]
+fn schema_valid_document() {
+    let res = schema_app().try_get_matches_from(vec!["prog", "--filter", r#"{"k":1}"#]);
+    assert!(res.is_ok());
+}
+
+#[test]
+fn schema_violating_document() {
+    let res = schema_app().try_get_matches_from(vec!["prog", "--filter", r#"{"k":"one"}"#]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    // the error cites the failing field
+    assert!(err.to_string().contains("'k'"), "{}", err);
+}
+
+#[test]
+fn schema_missing_required_field() {
+    let res = schema_app().try_get_matches_from(vec!["prog", "--filter", "{}"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("'k'"), "{}", err);
+}
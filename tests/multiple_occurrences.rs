@@ -1,4 +1,4 @@
-use clap::{App, Arg, ArgSettings};
+use clap::{App, Arg, ArgSettings, ErrorKind};
 
 #[test]
 fn multiple_occurrences_of_flags_long() {
@@ -128,3 +128,88 @@ fn multiple_occurrences_of_after_env() {
     assert!(m.is_ok(), "{}", m.unwrap_err());
     assert_eq!(m.unwrap().occurrences_of("verbose"), 3);
 }
+
+#[test]
+fn count_with_max_under_cap() {
+    let m = App::new("prog")
+        .arg(Arg::new("verbose").short('v').count_with_max(3))
+        .try_get_matches_from(vec!["prog", "-vvv"])
+        .unwrap();
+    assert_eq!(m.count_of("verbose"), 3);
+
+    let m = App::new("prog")
+        .arg(Arg::new("verbose").short('v').count_with_max(3))
+        .try_get_matches_from(vec!["prog"])
+        .unwrap();
+    assert_eq!(m.count_of("verbose"), 0);
+}
+
+#[test]
+fn count_with_max_over_cap() {
+    let res = App::new("prog")
+        .arg(Arg::new("verbose").short('v').count_with_max(3))
+        .try_get_matches_from(vec!["prog", "-vvvv"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::TooManyOccurrences);
+}
+
+#[test]
+fn max_occurrences_one_allows_single_use() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .takes_value(true)
+                .max_occurrences(1),
+        )
+        .try_get_matches_from(vec!["prog", "-o", "a.txt"])
+        .unwrap();
+    assert_eq!(m.value_of("output"), Some("a.txt"));
+}
+
+#[test]
+fn max_occurrences_one_errors_on_second_use() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .takes_value(true)
+                .max_occurrences(1),
+        )
+        .try_get_matches_from(vec!["prog", "-o", "a.txt", "-o", "b.txt"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::TooManyOccurrences);
+}
+
+#[test]
+fn max_occurrences_independent_of_multiple_values() {
+    // a single occurrence may still carry several values
+    let m = App::new("prog")
+        .arg(
+            Arg::new("include")
+                .short('I')
+                .takes_value(true)
+                .multiple_values(true)
+                .max_occurrences(1),
+        )
+        .try_get_matches_from(vec!["prog", "-I", "a", "b", "c"])
+        .unwrap();
+    assert_eq!(
+        m.values_of("include").unwrap().collect::<Vec<_>>(),
+        ["a", "b", "c"]
+    );
+}
+
+#[test]
+fn max_occurrences_over_one_caps_repeats() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .takes_value(true)
+                .max_occurrences(2),
+        )
+        .try_get_matches_from(vec!["prog", "-o", "a", "-o", "b", "-o", "c"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::TooManyOccurrences);
+}
@@ -1265,3 +1265,135 @@ fn issue_2229() {
     assert!(m.is_err()); // This panics, because `m.is_err() == false`.
     assert_eq!(m.unwrap_err().kind, ErrorKind::WrongNumberOfValues);
 }
+
+#[test]
+fn append_collects_across_occurrences() {
+    let m = App::new("prog")
+        .arg(Arg::new("include").short('I').append(true))
+        .try_get_matches_from(vec!["prog", "-I", "a", "-I", "b"]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    let m = m.unwrap();
+    assert_eq!(m.occurrences_of("include"), 2);
+    let includes: Vec<_> = m.values_of("include").unwrap().collect();
+    assert_eq!(includes, ["a", "b"]);
+}
+
+#[test]
+fn append_rejects_multiple_values_per_occurrence() {
+    let m = App::new("prog")
+        .arg(Arg::new("include").short('I').append(true))
+        .try_get_matches_from(vec!["prog", "-I", "a", "b"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn value_terminator_keep_includes_terminator() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("cmd")
+                .takes_value(true)
+                .multiple(true)
+                .value_terminator_keep(";"),
+        )
+        .arg(Arg::new("rest"))
+        .try_get_matches_from(vec!["prog", "a", "b", ";", "rest"]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    let m = m.unwrap();
+    let cmd: Vec<_> = m.values_of("cmd").unwrap().collect();
+    assert_eq!(cmd, ["a", "b", ";"]);
+    assert_eq!(m.value_of("rest"), Some("rest"));
+}
+
+#[test]
+fn value_terminator_keep_with_delimiter() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("cmd")
+                .long("cmd")
+                .use_delimiter(true)
+                .multiple(true)
+                .value_terminator_keep(";"),
+        )
+        .try_get_matches_from(vec!["prog", "--cmd", "a,b,;,c"]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    let m = m.unwrap();
+    let cmd: Vec<_> = m.values_of("cmd").unwrap().collect();
+    assert_eq!(cmd, ["a", "b", ";"]);
+}
+
+#[test]
+fn number_of_values_zero_behaves_as_flag() {
+    let m = App::new("prog")
+        .arg(Arg::new("verbose").long("verbose").number_of_values(0))
+        .try_get_matches_from(vec!["prog", "--verbose"]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    let m = m.unwrap();
+    assert!(m.is_present("verbose"));
+    assert!(m.value_of("verbose").is_none());
+}
+
+#[test]
+fn number_of_values_zero_missing_ok() {
+    let m = App::new("prog")
+        .arg(Arg::new("verbose").long("verbose").number_of_values(0))
+        .try_get_matches_from(vec!["prog"]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    assert!(!m.unwrap().is_present("verbose"));
+}
+
+#[test]
+fn value_labels_do_not_constrain_count() {
+    let m = App::new("myprog")
+        .arg(
+            Arg::new("range")
+                .long("range")
+                .multiple_values(true)
+                .value_labels(&["LOW", "HIGH"]),
+        )
+        .try_get_matches_from(vec!["myprog", "--range", "1", "5", "9"]);
+
+    assert!(m.is_ok());
+    let m = m.unwrap();
+    assert_eq!(
+        m.values_of("range").unwrap().collect::<Vec<_>>(),
+        ["1", "5", "9"]
+    );
+}
+
+#[test]
+fn value_names_still_constrain_count() {
+    let m = App::new("myprog")
+        .arg(
+            Arg::new("range")
+                .long("range")
+                .multiple_values(true)
+                .value_names(&["LOW", "HIGH"]),
+        )
+        .try_get_matches_from(vec!["myprog", "--range", "1", "5", "9"]);
+
+    // the implicit number_of_values(2) stops the option after two values, so "9" is left
+    // over with nowhere to go
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "Argument 'range' has 2 value names but number_of_values(3); the counts must match"]
+fn value_names_and_number_of_values_mismatch() {
+    let _ = App::new("myprog")
+        .arg(
+            Arg::new("range")
+                .long("range")
+                .value_names(&["LOW", "HIGH"])
+                .number_of_values(3),
+        )
+        .try_get_matches_from(vec!["myprog"]);
+}
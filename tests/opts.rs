@@ -551,3 +551,145 @@ fn issue_2279() {
 
     assert_eq!(after_help_heading.value_of("foo"), Some("bar"));
 }
+
+#[test]
+fn value_optional_present_without_value() {
+    let r = App::new("prog")
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .value_optional(true)
+                .default_missing_value("always"),
+        )
+        .try_get_matches_from(vec!["prog", "--color"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("color"));
+    assert_eq!(m.value_of("color"), Some("always"));
+}
+
+#[test]
+fn value_optional_present_with_value() {
+    let r = App::new("prog")
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .value_optional(true)
+                .default_missing_value("always"),
+        )
+        .try_get_matches_from(vec!["prog", "--color", "never"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("color"), Some("never"));
+}
+
+#[test]
+fn value_optional_no_default_missing_value() {
+    let r = App::new("prog")
+        .arg(Arg::new("color").long("color").value_optional(true))
+        .try_get_matches_from(vec!["prog", "--color"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("color"));
+    assert_eq!(m.value_of("color"), None);
+}
+
+#[test]
+fn value_optional_help_brackets() {
+    static VALUE_OPTIONAL_HELP: &str = "prog 
+
+USAGE:
+    prog [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+        --color[=<color>]    ";
+
+    let app = App::new("prog").arg(Arg::new("color").long("color").value_optional(true));
+
+    assert!(utils::compare_output(
+        app,
+        "prog --help",
+        VALUE_OPTIONAL_HELP,
+        false
+    ));
+}
+
+#[test]
+fn values_from_lines_splits_value() {
+    let m = App::new("prog")
+        .arg(Arg::new("hosts").long("hosts").values_from_lines())
+        .try_get_matches_from(vec!["prog", "--hosts", "alpha\nbeta\ngamma"])
+        .unwrap();
+    assert_eq!(
+        m.values_of("hosts").unwrap().collect::<Vec<_>>(),
+        ["alpha", "beta", "gamma"]
+    );
+    assert_eq!(m.occurrences_of("hosts"), 1);
+}
+
+#[test]
+fn values_from_lines_skips_empty_lines_and_crlf() {
+    let m = App::new("prog")
+        .arg(Arg::new("hosts").long("hosts").values_from_lines())
+        .try_get_matches_from(vec!["prog", "--hosts", "alpha\r\n\nbeta\n"])
+        .unwrap();
+    assert_eq!(
+        m.values_of("hosts").unwrap().collect::<Vec<_>>(),
+        ["alpha", "beta"]
+    );
+}
+
+#[test]
+fn key_value_delimiter_splits_pair() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("env")
+                .long("env")
+                .multiple_occurrences(true)
+                .key_value_delimiter('='),
+        )
+        .try_get_matches_from(vec!["prog", "--env", "FOO=bar=baz"])
+        .unwrap();
+
+    // Only the first delimiter splits; the rest belongs to the value
+    assert_eq!(m.key_value_of("env"), Some(("FOO", "bar=baz")));
+}
+
+#[test]
+fn key_value_delimiter_missing_delim_errors() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("env")
+                .long("env")
+                .setting(ArgSettings::MultipleValues)
+                .key_value_delimiter('='),
+        )
+        .try_get_matches_from(vec!["prog", "--env", "FOO=bar", "baz"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::ValueValidation);
+}
+
+#[test]
+fn key_value_delimiter_custom_char() {
+    let m = App::new("prog")
+        .arg(Arg::new("def").short('D').key_value_delimiter(':'))
+        .try_get_matches_from(vec!["prog", "-D", "name:def"])
+        .unwrap();
+
+    assert_eq!(m.key_value_of("def"), Some(("name", "def")));
+}
+
+#[test]
+fn key_value_of_absent_arg() {
+    let m = App::new("prog")
+        .arg(Arg::new("env").long("env").key_value_delimiter('='))
+        .try_get_matches_from(vec!["prog"])
+        .unwrap();
+
+    assert_eq!(m.key_value_of("env"), None);
+}
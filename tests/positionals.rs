@@ -296,3 +296,51 @@ fn positional_arg_with_short() {
         .arg(Arg::new("arg").index(1).short('a'))
         .try_get_matches();
 }
+
+#[test]
+#[should_panic = "positional indices start at 1"]
+fn positional_arg_index_zero() {
+    use clap::Arg;
+
+    let _ = Arg::new("x").index(0);
+}
+
+#[test]
+fn last_positional_custom_separator() {
+    let r = App::new("test")
+        .arg("<TARGET> 'some target'")
+        .arg("[CORPUS] 'some corpus'")
+        .arg(Arg::from("[ARGS]... 'some file'").last_separator("++"))
+        .try_get_matches_from(vec!["test", "tgt", "++", "arg1", "arg2"]);
+    assert!(r.is_ok(), "{:?}", r.unwrap_err().kind);
+    let m = r.unwrap();
+    assert_eq!(m.value_of("TARGET"), Some("tgt"));
+    assert_eq!(
+        m.values_of("ARGS").unwrap().collect::<Vec<_>>(),
+        &["arg1", "arg2"]
+    );
+}
+
+#[test]
+fn last_positional_custom_separator_keeps_double_dash() {
+    let r = App::new("test")
+        .arg("<TARGET> 'some target'")
+        .arg(Arg::from("[ARGS]... 'some file'").last_separator("++"))
+        .try_get_matches_from(vec!["test", "tgt", "--", "arg"]);
+    assert!(r.is_ok(), "{:?}", r.unwrap_err().kind);
+    assert_eq!(
+        r.unwrap().values_of("ARGS").unwrap().collect::<Vec<_>>(),
+        &["arg"]
+    );
+}
+
+#[test]
+fn last_positional_custom_separator_required_without_it() {
+    let r = App::new("test")
+        .arg("<TARGET> 'some target'")
+        .arg("[CORPUS] 'some corpus'")
+        .arg(Arg::from("[ARGS]... 'some file'").last_separator("++"))
+        .try_get_matches_from(vec!["test", "tgt", "crp", "arg"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
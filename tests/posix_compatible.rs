@@ -306,3 +306,82 @@ fn require_overridden_4() {
     let err = result.err().unwrap();
     assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
 }
+
+#[test]
+fn overrides_with_everything() {
+    let m = App::new("test")
+        .arg(Arg::from("--mode-a 'first mode'"))
+        .arg(Arg::from("--mode-b 'second mode'"))
+        .arg(Arg::from("--mode-c 'third mode'").overrides_with_everything(true))
+        .get_matches_from(vec!["test", "--mode-a", "--mode-b", "--mode-c"]);
+    assert!(m.is_present("mode-c"));
+    assert!(!m.is_present("mode-a"));
+    assert!(!m.is_present("mode-b"));
+}
+
+#[test]
+fn overrides_with_everything_not_used() {
+    // args given before the overriding flag are unaffected when it's absent
+    let m = App::new("test")
+        .arg(Arg::from("--mode-a 'first mode'"))
+        .arg(Arg::from("--mode-b 'second mode'"))
+        .arg(Arg::from("--mode-c 'third mode'").overrides_with_everything(true))
+        .get_matches_from(vec!["test", "--mode-a", "--mode-b"]);
+    assert!(m.is_present("mode-a"));
+    assert!(m.is_present("mode-b"));
+    assert!(!m.is_present("mode-c"));
+}
+
+#[test]
+fn get_arg_overrides_resolves_targets() {
+    let mut app = App::new("prog")
+        .arg(Arg::new("flag").long("flag").overrides_with("color"))
+        .arg(Arg::new("color").long("color"));
+    app._build();
+
+    let flag = app.get_arguments().find(|a| a.get_name() == "flag").unwrap();
+    let overridden: Vec<_> = app
+        .get_arg_overrides(flag)
+        .iter()
+        .map(|a| a.get_name())
+        .collect();
+    assert_eq!(overridden, ["color"]);
+}
+
+#[test]
+fn self_override_resets_multiple_values() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("set")
+                .long("set")
+                .takes_value(true)
+                .multiple(true)
+                .overrides_with("set")
+                .allow_self_override(true),
+        )
+        .try_get_matches_from(vec!["prog", "--set", "a", "b", "--set", "c"]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    let m = m.unwrap();
+    assert_eq!(m.occurrences_of("set"), 1);
+    assert_eq!(m.values_of("set").unwrap().collect::<Vec<_>>(), ["c"]);
+}
+
+#[test]
+fn self_override_multiple_values_stays_off_by_default() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("set")
+                .long("set")
+                .takes_value(true)
+                .multiple(true)
+                .overrides_with("set"),
+        )
+        .try_get_matches_from(vec!["prog", "--set", "a", "b", "--set", "c"]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    assert_eq!(
+        m.unwrap().values_of("set").unwrap().collect::<Vec<_>>(),
+        ["a", "b", "c"]
+    );
+}
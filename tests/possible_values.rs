@@ -271,6 +271,48 @@ fn case_insensitive_multiple() {
     );
 }
 
+#[test]
+fn case_insensitive_normalized() {
+    let m = App::new("pv")
+        .arg(
+            Arg::new("option")
+                .short('o')
+                .long("--option")
+                .takes_value(true)
+                .possible_value("Fast")
+                .possible_value("Slow")
+                .case_insensitive(true)
+                .normalize_case(true),
+        )
+        .try_get_matches_from(vec!["pv", "--option", "fAsT"]);
+
+    assert!(m.is_ok());
+    assert_eq!(m.unwrap().value_of("option"), Some("Fast"));
+}
+
+#[test]
+fn case_insensitive_normalized_multiple() {
+    let m = App::new("pv")
+        .arg(
+            Arg::new("option")
+                .short('o')
+                .long("--option")
+                .takes_value(true)
+                .possible_value("Fast")
+                .possible_value("Slow")
+                .multiple(true)
+                .case_insensitive(true)
+                .normalize_case(true),
+        )
+        .try_get_matches_from(vec!["pv", "--option", "FAST", "slow"]);
+
+    assert!(m.is_ok());
+    assert_eq!(
+        m.unwrap().values_of("option").unwrap().collect::<Vec<_>>(),
+        &["Fast", "Slow"]
+    );
+}
+
 #[test]
 fn case_insensitive_multiple_fail() {
     let m = App::new("pv")
@@ -288,3 +330,220 @@ fn case_insensitive_multiple_fail() {
     assert!(m.is_err());
     assert_eq!(m.unwrap_err().kind, ErrorKind::InvalidValue);
 }
+
+#[test]
+#[cfg(feature = "derive")]
+fn possible_values_from_arg_enum() {
+    use clap::ArgEnum;
+
+    enum Format {
+        Json,
+        Yaml,
+    }
+
+    impl ArgEnum for Format {
+        const VARIANTS: &'static [&'static str] = &["json", "yaml"];
+
+        fn from_str(input: &str, _case_insensitive: bool) -> Result<Self, String> {
+            match input {
+                i if i.eq_ignore_ascii_case("json") => Ok(Format::Json),
+                i if i.eq_ignore_ascii_case("yaml") => Ok(Format::Yaml),
+                _ => Err(format!("Invalid variant: {}", input)),
+            }
+        }
+    }
+
+    let m = App::new("prog")
+        .arg(Arg::new("format").long("format").possible_values_from::<Format>())
+        .try_get_matches_from(vec!["prog", "--format", "JSON"]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    assert_eq!(m.unwrap().value_of("format"), Some("JSON"));
+
+    let m = App::new("prog")
+        .arg(Arg::new("format").long("format").possible_values_from::<Format>())
+        .try_get_matches_from(vec!["prog", "--format", "toml"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn possible_values_with_help_getter() {
+    let arg = Arg::new("mode")
+        .long("mode")
+        .possible_value_with_help("fast", "finishes quickly")
+        .possible_value_with_help("slow", "takes its time");
+    assert_eq!(
+        arg.get_possible_values_help(),
+        Some(&[("fast", "finishes quickly"), ("slow", "takes its time")][..])
+    );
+    assert_eq!(arg.get_possible_values(), Some(&["fast", "slow"][..]));
+
+    let plain = Arg::new("mode").long("mode").possible_values(&["fast", "slow"]);
+    assert_eq!(plain.get_possible_values_help(), None);
+    assert_eq!(plain.get_possible_values(), Some(&["fast", "slow"][..]));
+}
+
+#[test]
+fn possible_value_with_help_still_validates() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("mode")
+                .long("mode")
+                .possible_value_with_help("fast", "finishes quickly")
+                .possible_value_with_help("slow", "takes its time"),
+        )
+        .try_get_matches_from(vec!["prog", "--mode", "wrong"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+static ALIASED_PV_HELP: &str = "test 
+
+USAGE:
+    test [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+        --level <level>    [possible values: verbose, quiet]
+";
+
+#[test]
+fn possible_value_aliased_stores_canonical() {
+    let m = App::new("pv")
+        .arg(
+            Arg::new("level")
+                .long("level")
+                .possible_value_aliased("verbose", &["v"])
+                .possible_value("quiet"),
+        )
+        .get_matches_from(vec!["pv", "--level", "v"]);
+
+    assert_eq!(m.value_of("level"), Some("verbose"));
+}
+
+#[test]
+fn possible_value_aliased_help_lists_canonical_only() {
+    assert!(utils::compare_output(
+        App::new("test").arg(
+            Arg::new("level")
+                .long("level")
+                .possible_value_aliased("verbose", &["v"])
+                .possible_value("quiet")
+        ),
+        "test --help",
+        ALIASED_PV_HELP,
+        false
+    ));
+}
+
+#[test]
+fn possible_value_aliased_invalid_value() {
+    let m = App::new("pv")
+        .arg(
+            Arg::new("level")
+                .long("level")
+                .possible_value_aliased("verbose", &["v"]),
+        )
+        .try_get_matches_from(vec!["pv", "--level", "loud"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[cfg(feature = "suggestions")]
+static PV_ERROR_LONG: &str = "error: \"slwo\" isn't a valid value for '--mode <mode>'
+\t[possible values: fast, slow]
+
+\tDid you mean \"slow\"?
+
+USAGE:
+    clap-test --mode <mode>
+
+For more information try --help";
+
+#[cfg(feature = "suggestions")]
+#[test]
+fn possible_values_long_suggests_closest() {
+    let app = App::new("clap-test").arg(
+        Arg::new("mode")
+            .long("mode")
+            .takes_value(true)
+            .possible_values(&["fast", "slow"]),
+    );
+
+    assert!(utils::compare_output(
+        app,
+        "clap-test --mode slwo",
+        PV_ERROR_LONG,
+        true
+    ));
+}
+
+static HIDE_PV_ENV_UNSET: &str = "test 1.4
+
+USAGE:
+    test [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+    -m, --mode <mode>    pick a mode";
+
+static HIDE_PV_ENV_SET: &str = "test 1.4
+
+USAGE:
+    test [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+    -m, --mode <mode>    pick a mode [possible values: fast, slow]";
+
+fn hide_pv_unless_env_app(var: &'static str) -> App<'static> {
+    App::new("test").version("1.4").arg(
+        Arg::from("-m, --mode [mode] 'pick a mode'")
+            .possible_values(&["fast", "slow"])
+            .hide_possible_values_unless_env(var),
+    )
+}
+
+#[test]
+fn hide_possible_values_unless_env_var_unset() {
+    std::env::remove_var("CLP_TEST_SHOW_PV");
+    assert!(utils::compare_output(
+        hide_pv_unless_env_app("CLP_TEST_SHOW_PV"),
+        "test --help",
+        HIDE_PV_ENV_UNSET,
+        false
+    ));
+}
+
+#[test]
+fn hide_possible_values_unless_env_var_set() {
+    std::env::set_var("CLP_TEST_SHOW_PV_SET", "1");
+    assert!(utils::compare_output(
+        hide_pv_unless_env_app("CLP_TEST_SHOW_PV_SET"),
+        "test --help",
+        HIDE_PV_ENV_SET,
+        false
+    ));
+}
+
+#[test]
+fn hide_possible_values_unless_env_still_matches() {
+    std::env::remove_var("CLP_TEST_SHOW_PV_PARSE");
+    let m = hide_pv_unless_env_app("CLP_TEST_SHOW_PV_PARSE")
+        .try_get_matches_from(vec!["test", "--mode", "wrong"]);
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::InvalidValue);
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "prompt")]
+
+use clap::{App, Arg, ErrorKind};
+
+#[test]
+fn prompt_if_missing_falls_back_when_not_tty() {
+    // The test harness runs without a TTY on stdin, so the prompt must not
+    // fire and the usual missing-argument error is produced.
+    let res = App::new("prog")
+        .arg(
+            Arg::new("token")
+                .long("token")
+                .takes_value(true)
+                .required(true)
+                .prompt_if_missing("Enter your API token"),
+        )
+        .try_get_matches_from(vec!["prog"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn prompt_if_missing_unused_when_arg_given() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("token")
+                .long("token")
+                .takes_value(true)
+                .required(true)
+                .prompt_if_missing("Enter your API token"),
+        )
+        .try_get_matches_from(vec!["prog", "--token", "abc"])
+        .expect("match failed");
+
+    assert_eq!(m.value_of("token"), Some("abc"));
+}
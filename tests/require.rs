@@ -452,6 +452,67 @@ fn requires_if_present_val() {
     assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
 }
 
+#[test]
+fn requires_if_not_other_val() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("mode")
+                .requires_if_not("default", "config")
+                .takes_value(true)
+                .long("mode"),
+        )
+        .arg(Arg::new("config").takes_value(true).long("config"))
+        .try_get_matches_from(vec!["prog", "--mode", "custom"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn requires_if_not_exempt_val_pass() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("mode")
+                .requires_if_not("default", "config")
+                .takes_value(true)
+                .long("mode"),
+        )
+        .arg(Arg::new("config").takes_value(true).long("config"))
+        .try_get_matches_from(vec!["prog", "--mode", "default"]);
+
+    assert!(res.is_ok());
+}
+
+#[test]
+fn requires_if_not_other_val_with_required_present_pass() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("mode")
+                .requires_if_not("default", "config")
+                .takes_value(true)
+                .long("mode"),
+        )
+        .arg(Arg::new("config").takes_value(true).long("config"))
+        .try_get_matches_from(vec!["prog", "--mode", "custom", "--config", "my.cfg"]);
+
+    assert!(res.is_ok());
+}
+
+#[test]
+fn requires_if_not_absent_pass() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("mode")
+                .requires_if_not("default", "config")
+                .takes_value(true)
+                .long("mode"),
+        )
+        .arg(Arg::new("config").takes_value(true).long("config"))
+        .try_get_matches_from(vec!["prog"]);
+
+    assert!(res.is_ok());
+}
+
 #[test]
 fn requires_if_present_mult() {
     let res = App::new("unlessone")
@@ -485,6 +546,55 @@ fn requires_if_present_mult_pass() {
     assert!(res.is_ok());
 }
 
+#[test]
+fn requires_all_if_present_val() {
+    let res = App::new("unlessone")
+        .arg(
+            Arg::new("cfg")
+                .requires_all_if("full", &["extra", "other"])
+                .takes_value(true)
+                .long("config"),
+        )
+        .arg(Arg::new("extra").long("extra"))
+        .arg(Arg::new("other").long("other"))
+        .try_get_matches_from(vec!["unlessone", "--config=full", "--extra"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn requires_all_if_present_val_pass() {
+    let res = App::new("unlessone")
+        .arg(
+            Arg::new("cfg")
+                .requires_all_if("full", &["extra", "other"])
+                .takes_value(true)
+                .long("config"),
+        )
+        .arg(Arg::new("extra").long("extra"))
+        .arg(Arg::new("other").long("other"))
+        .try_get_matches_from(vec!["unlessone", "--config=full", "--extra", "--other"]);
+
+    assert!(res.is_ok());
+}
+
+#[test]
+fn requires_all_if_other_val_pass() {
+    let res = App::new("unlessone")
+        .arg(
+            Arg::new("cfg")
+                .requires_all_if("full", &["extra", "other"])
+                .takes_value(true)
+                .long("config"),
+        )
+        .arg(Arg::new("extra").long("extra"))
+        .arg(Arg::new("other").long("other"))
+        .try_get_matches_from(vec!["unlessone", "--config=lite"]);
+
+    assert!(res.is_ok());
+}
+
 #[test]
 fn requires_if_present_val_no_present_pass() {
     let res = App::new("unlessone")
@@ -1028,3 +1138,164 @@ fn required_unless_invalid_arg() {
         )
         .try_get_matches_from(vec![""]);
 }
+
+#[test]
+#[cfg(unix)]
+fn requires_if_os_non_utf8_val() {
+    use std::ffi::{OsStr, OsString};
+    use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+    let app = || {
+        App::new("prog")
+            .arg(
+                Arg::new("cfg")
+                    .takes_value(true)
+                    .requires_if_os(OsStr::from_bytes(&[0xe9, b'.', b'c', b'f', b'g']), "input")
+                    .long("config"),
+            )
+            .arg(Arg::new("input"))
+    };
+    let res = app().try_get_matches_from(vec![
+        OsString::from("prog"),
+        OsString::from("--config"),
+        OsString::from_vec(vec![0xe9, b'.', b'c', b'f', b'g']),
+    ]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+
+    let res = app().try_get_matches_from(vec![
+        OsString::from("prog"),
+        OsString::from("--config"),
+        OsString::from_vec(vec![0xe9, b'.', b'c', b'f', b'g']),
+        OsString::from("other"),
+    ]);
+    assert!(res.is_ok(), "{:?}", res.unwrap_err().kind);
+}
+
+#[test]
+fn get_arg_requires_resolves_targets() {
+    let mut app = App::new("prog")
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .requires("input")
+                .requires_if("special", "output"),
+        )
+        .arg(Arg::new("input").index(1))
+        .arg(Arg::new("output").index(2));
+    app._build();
+
+    let config = app
+        .get_arguments()
+        .find(|a| a.get_name() == "config")
+        .unwrap();
+    let required: Vec<_> = app
+        .get_arg_requires(config)
+        .iter()
+        .map(|a| a.get_name())
+        .collect();
+    assert_eq!(required, ["input", "output"]);
+}
+
+fn required_if_group_present_app() -> App<'static> {
+    App::new("prog")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .takes_value(true)
+                .required_if_group_present("output"),
+        )
+        .arg(Arg::new("json").long("json"))
+        .arg(Arg::new("yaml").long("yaml"))
+        .group(ArgGroup::new("output").args(&["json", "yaml"]))
+}
+
+#[test]
+fn required_if_group_present_err() {
+    let res = required_if_group_present_app().try_get_matches_from(vec!["prog", "--yaml"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn required_if_group_present_ok() {
+    let res =
+        required_if_group_present_app().try_get_matches_from(vec!["prog", "--json", "--format", "pretty"]);
+
+    assert!(res.is_ok(), "{:?}", res.unwrap_err());
+}
+
+#[test]
+fn required_if_group_absent_not_required() {
+    let res = required_if_group_present_app().try_get_matches_from(vec!["prog"]);
+
+    assert!(res.is_ok(), "{:?}", res.unwrap_err());
+}
+
+#[test]
+fn requires_satisfied_by_default_value() {
+    let res = App::new("prog")
+        .arg(Arg::new("cfg").long("config").requires("input"))
+        .arg(Arg::new("input").long("input").default_value("-"))
+        .try_get_matches_from(vec!["prog", "--config"]);
+
+    assert!(res.is_ok());
+    let m = res.unwrap();
+    assert_eq!(m.value_of("input"), Some("-"));
+}
+
+#[test]
+fn requires_explicit_rejects_defaulted_target() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("cfg")
+                .long("config")
+                .requires("input")
+                .requires_explicit(true),
+        )
+        .arg(Arg::new("input").long("input").default_value("-"))
+        .try_get_matches_from(vec!["prog", "--config"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn requires_explicit_accepts_supplied_target() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("cfg")
+                .long("config")
+                .requires("input")
+                .requires_explicit(true),
+        )
+        .arg(Arg::new("input").long("input").default_value("-").takes_value(true))
+        .try_get_matches_from(vec!["prog", "--config", "--input", "file"]);
+
+    assert!(res.is_ok());
+    assert_eq!(res.unwrap().value_of("input"), Some("file"));
+}
+
+#[test]
+fn required_if_eq_all_token_scenario() {
+    // --token is required only when --auth=oauth AND --remote=true hold together
+    let app = || {
+        App::new("ri")
+            .arg(
+                Arg::new("token")
+                    .long("token")
+                    .takes_value(true)
+                    .required_if_eq_all(&[("auth", "oauth"), ("remote", "true")]),
+            )
+            .arg(Arg::new("auth").long("auth").takes_value(true))
+            .arg(Arg::new("remote").long("remote").takes_value(true))
+    };
+
+    let res = app().try_get_matches_from(vec!["ri", "--auth", "oauth"]);
+    assert!(res.is_ok(), "{:?}", res.unwrap_err().kind);
+
+    let res = app().try_get_matches_from(vec!["ri", "--auth", "oauth", "--remote", "true"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "tty_default")]
+
+use clap::{App, Arg};
+
+#[test]
+fn tty_default_applied_when_predicate_true() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("progress")
+                .long("progress")
+                .default_value_if_tty_with("auto", || true),
+        )
+        .try_get_matches_from(vec!["prog"])
+        .unwrap();
+
+    assert_eq!(m.value_of("progress"), Some("auto"));
+}
+
+#[test]
+fn tty_default_skipped_when_predicate_false() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("progress")
+                .long("progress")
+                .default_value_if_tty_with("auto", || false),
+        )
+        .try_get_matches_from(vec!["prog"])
+        .unwrap();
+
+    assert!(!m.is_present("progress"));
+    assert_eq!(m.value_of("progress"), None);
+}
+
+#[test]
+fn tty_default_never_overrides_cli_value() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("progress")
+                .long("progress")
+                .default_value_if_tty_with("auto", || true),
+        )
+        .try_get_matches_from(vec!["prog", "--progress", "always"])
+        .unwrap();
+
+    assert_eq!(m.value_of("progress"), Some("always"));
+}
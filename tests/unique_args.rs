@@ -30,3 +30,16 @@ fn unique_arg_longs() {
         .args(&[Arg::new("arg1").long("long"), Arg::new("arg2").long("long")])
         .try_get_matches();
 }
+
+#[test]
+fn with_id_decouples_lookup_from_display_name() {
+    use clap::{App, Arg};
+    let app = App::new("prog")
+        .arg(Arg::new("value").with_id("a_value").takes_value(true).long("a"))
+        .arg(Arg::new("value").with_id("b_value").takes_value(true).long("b"));
+    let m = app
+        .try_get_matches_from(vec!["prog", "--a", "one", "--b", "two"])
+        .unwrap();
+    assert_eq!(m.value_of("a_value"), Some("one"));
+    assert_eq!(m.value_of("b_value"), Some("two"));
+}
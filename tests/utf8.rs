@@ -339,3 +339,58 @@ fn allow_invalid_utf8_subcommand_args_with_allow_external_subcommands() {
         ]
     );
 }
+
+#[test]
+fn allow_invalid_utf8_keeps_raw_value() {
+    let m = App::new("bad_utf8")
+        .arg(Arg::new("path").index(1).allow_invalid_utf8(true))
+        .try_get_matches_from(vec![
+            OsString::from("myprog"),
+            OsString::from_vec(vec![b'/', b't', b'm', b'p', b'/', 0xe9]),
+        ]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    let m = m.unwrap();
+    assert_eq!(m.value_of("path"), None);
+    assert_eq!(
+        m.value_of_os("path"),
+        Some(&*OsString::from_vec(vec![b'/', b't', b'm', b'p', b'/', 0xe9]))
+    );
+}
+
+#[test]
+fn allow_invalid_utf8_exempt_from_strict_utf8() {
+    let m = App::new("bad_utf8")
+        .setting(AppSettings::StrictUtf8)
+        .arg(Arg::new("path").index(1).allow_invalid_utf8(true))
+        .try_get_matches_from(vec![
+            OsString::from("myprog"),
+            OsString::from_vec(vec![0xe9]),
+        ]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+
+    let m = App::new("bad_utf8")
+        .setting(AppSettings::StrictUtf8)
+        .arg(Arg::new("path").index(1))
+        .try_get_matches_from(vec![
+            OsString::from("myprog"),
+            OsString::from_vec(vec![0xe9]),
+        ]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::InvalidUtf8);
+}
+
+#[test]
+fn allow_invalid_utf8_valid_value_still_str_accessible() {
+    let m = App::new("bad_utf8")
+        .arg(Arg::new("path").index(1).allow_invalid_utf8(true))
+        .try_get_matches_from(vec![
+            OsString::from("myprog"),
+            OsString::from("/tmp/ok"),
+        ])
+        .unwrap();
+
+    assert_eq!(m.value_of("path"), Some("/tmp/ok"));
+}
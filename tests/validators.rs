@@ -1,23 +1,5 @@
 use clap::{App, Arg};
 
-#[cfg(debug_assertions)]
-#[test]
-#[should_panic = "Argument 'test' has both `validator` and `validator_os` set which is not allowed"]
-fn both_validator_and_validator_os() {
-    let _ = App::new("test")
-        .arg(
-            Arg::new("test")
-                .validator(|val| val.parse::<u32>().map_err(|e| e.to_string()))
-                .validator_os(|val| {
-                    val.to_str()
-                        .unwrap()
-                        .parse::<u32>()
-                        .map_err(|e| e.to_string())
-                }),
-        )
-        .try_get_matches_from(&["app", "1"]);
-}
-
 #[test]
 fn test_validator_fromstr_trait() {
     use std::str::FromStr;
@@ -51,6 +33,42 @@ fn test_validator_msg_newline() {
     assert!(msg.ends_with('\n'));
 }
 
+#[test]
+fn test_validator_all_unique() {
+    fn unique(vals: &[&str]) -> Result<(), String> {
+        for (i, v) in vals.iter().enumerate() {
+            if vals[..i].contains(v) {
+                return Err(format!("The value '{}' was given more than once", v));
+            }
+        }
+        Ok(())
+    }
+
+    let app = || {
+        App::new("test").arg(
+            Arg::new("tags")
+                .long("tags")
+                .use_delimiter(true)
+                .multiple(true)
+                .validator_all(unique),
+        )
+    };
+
+    let res = app().try_get_matches_from(&["app", "--tags", "a,b"]);
+    assert!(res.is_ok());
+
+    let res = app().try_get_matches_from(&["app", "--tags", "a,a"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, clap::ErrorKind::ValueValidation);
+    assert!(
+        err.to_string()
+            .contains("The value 'a' was given more than once"),
+        "{}",
+        err
+    );
+}
+
 #[test]
 fn stateful_validator() {
     let mut state = false;
@@ -64,3 +82,688 @@ fn stateful_validator() {
 
     assert!(state);
 }
+
+#[test]
+fn require_increasing_values_ok() {
+    let res = App::new("test")
+        .arg(
+            Arg::new("breakpoints")
+                .long("breakpoints")
+                .takes_value(true)
+                .multiple_values(true)
+                .require_increasing_values(true),
+        )
+        .try_get_matches_from(&["app", "--breakpoints", "1", "2", "3"]);
+    assert!(res.is_ok());
+}
+
+#[test]
+fn require_increasing_values_out_of_order() {
+    let res = App::new("test")
+        .arg(
+            Arg::new("breakpoints")
+                .long("breakpoints")
+                .takes_value(true)
+                .multiple_values(true)
+                .require_increasing_values(true),
+        )
+        .try_get_matches_from(&["app", "--breakpoints", "1", "3", "2"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, clap::ErrorKind::ValueValidation);
+    assert!(
+        err.to_string().contains("'3' is followed by '2'"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn require_increasing_values_equal() {
+    // equal neighbours fail, since the sequence must be *strictly* increasing
+    let res = App::new("test")
+        .arg(
+            Arg::new("breakpoints")
+                .long("breakpoints")
+                .takes_value(true)
+                .multiple_values(true)
+                .require_increasing_values(true),
+        )
+        .try_get_matches_from(&["app", "--breakpoints", "1", "1", "2"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, clap::ErrorKind::ValueValidation);
+    assert!(
+        err.to_string().contains("'1' is followed by '1'"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn require_increasing_values_non_numeric() {
+    let res = App::new("test")
+        .arg(
+            Arg::new("breakpoints")
+                .long("breakpoints")
+                .takes_value(true)
+                .multiple_values(true)
+                .require_increasing_values(true),
+        )
+        .try_get_matches_from(&["app", "--breakpoints", "1", "two"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, clap::ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("not a number"), "{}", err);
+}
+
+#[test]
+fn require_increasing_values_non_finite() {
+    let res = App::new("test")
+        .arg(
+            Arg::new("breakpoints")
+                .long("breakpoints")
+                .takes_value(true)
+                .multiple_values(true)
+                .require_increasing_values(true),
+        )
+        .try_get_matches_from(&["app", "--breakpoints", "5", "NaN", "1"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, clap::ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("not a number"), "{}", err);
+}
+
+#[test]
+fn aggregate_value_errors_reports_all() {
+    let res = App::new("test")
+        .setting(clap::AppSettings::AggregateValueErrors)
+        .arg(
+            Arg::new("width")
+                .long("width")
+                .takes_value(true)
+                .validator(|val| val.parse::<u32>().map_err(|e| e.to_string())),
+        )
+        .arg(
+            Arg::new("height")
+                .long("height")
+                .takes_value(true)
+                .validator(|val| val.parse::<u32>().map_err(|e| e.to_string())),
+        )
+        .try_get_matches_from(&["app", "--width", "x", "--height", "y"]);
+
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, clap::ErrorKind::ValueValidation);
+    let msg = err.to_string();
+    assert!(msg.contains("--width"), "{}", msg);
+    assert!(msg.contains("--height"), "{}", msg);
+}
+
+#[test]
+fn aggregate_value_errors_single_error_unchanged() {
+    let res = App::new("test")
+        .setting(clap::AppSettings::AggregateValueErrors)
+        .arg(
+            Arg::new("width")
+                .long("width")
+                .takes_value(true)
+                .validator(|val| val.parse::<u32>().map_err(|e| e.to_string())),
+        )
+        .try_get_matches_from(&["app", "--width", "x"]);
+
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, clap::ErrorKind::ValueValidation);
+    assert!(
+        err.to_string()
+            .contains("Invalid value for '--width <width>'"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn map_values_sorts_and_dedups() {
+    let m = App::new("test")
+        .arg(
+            Arg::new("tags")
+                .long("tags")
+                .takes_value(true)
+                .multiple_values(true)
+                .map_values(|mut vals| {
+                    vals.sort();
+                    vals.dedup();
+                    vals
+                }),
+        )
+        .try_get_matches_from(&["app", "--tags", "b", "a", "b"])
+        .expect("match failed");
+
+    let vals: Vec<_> = m.values_of("tags").unwrap().collect();
+    assert_eq!(vals, ["a", "b"]);
+}
+
+#[test]
+fn map_values_runs_after_validators() {
+    let res = App::new("test")
+        .arg(
+            Arg::new("nums")
+                .long("nums")
+                .takes_value(true)
+                .multiple_values(true)
+                .validator(|val| val.parse::<u32>().map_err(|e| e.to_string()))
+                .map_values(|vals| vals),
+        )
+        .try_get_matches_from(&["app", "--nums", "1", "x"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, clap::ErrorKind::ValueValidation);
+}
+
+#[test]
+fn map_values_preserves_occurrence_groups() {
+    let m = App::new("test")
+        .arg(
+            Arg::new("point")
+                .long("point")
+                .takes_value(true)
+                .number_of_values(2)
+                .multiple_occurrences(true)
+                .map_values(|vals| vals.into_iter().map(|v| format!("<{}>", v)).collect()),
+        )
+        .try_get_matches_from(&["app", "--point", "1", "2", "--point", "3", "4"])
+        .expect("match failed");
+
+    let grouped: Vec<Vec<&str>> = m.grouped_values_of("point").unwrap().collect();
+    assert_eq!(grouped, vec![vec!["<1>", "<2>"], vec!["<3>", "<4>"]]);
+}
+
+#[test]
+fn validation_exit_code_carried_on_error() {
+    let res = App::new("exit_code")
+        .arg(
+            Arg::new("unsafe-op")
+                .long("unsafe-op")
+                .takes_value(true)
+                .validator(|s| s.parse::<u32>().map_err(|e| e.to_string()))
+                .validation_exit_code(3),
+        )
+        .try_get_matches_from(vec!["exit_code", "--unsafe-op", "nope"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, clap::ErrorKind::ValueValidation);
+    assert_eq!(err.exit_code, Some(3));
+}
+
+#[test]
+fn validation_exit_code_not_set_by_default() {
+    let res = App::new("exit_code")
+        .arg(
+            Arg::new("unsafe-op")
+                .long("unsafe-op")
+                .takes_value(true)
+                .validator(|s| s.parse::<u32>().map_err(|e| e.to_string())),
+        )
+        .try_get_matches_from(vec!["exit_code", "--unsafe-op", "nope"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().exit_code, None);
+}
+
+#[test]
+fn validator_with_suggestions_renders_did_you_mean() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .takes_value(true)
+                .validator_with_suggestions(|s| {
+                    let known = ["json", "yaml", "toml"];
+                    if known.contains(&s) {
+                        Ok(())
+                    } else {
+                        Err((
+                            format!("unknown format '{}'", s),
+                            known.iter().map(|k| k.to_string()).collect(),
+                        ))
+                    }
+                }),
+        )
+        .try_get_matches_from(vec!["prog", "--format", "jsn"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, clap::ErrorKind::ValueValidation);
+    let display = err.to_string();
+    assert!(display.contains("unknown format 'jsn'"), "{}", display);
+    assert!(display.contains("Did you mean 'json'?"), "{}", display);
+}
+
+#[test]
+fn validator_with_suggestions_ok_value_passes() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .takes_value(true)
+                .validator_with_suggestions(|s| {
+                    if s == "json" {
+                        Ok(())
+                    } else {
+                        Err(("bad".to_string(), vec![]))
+                    }
+                }),
+        )
+        .try_get_matches_from(vec!["prog", "--format", "json"]);
+    assert!(res.is_ok());
+}
+
+#[test]
+fn validator_os_runs_before_str_validator() {
+    use std::sync::{Arc, Mutex};
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let os_order = Arc::clone(&order);
+    let str_order = Arc::clone(&order);
+
+    let m = App::new("prog")
+        .arg(
+            Arg::new("file")
+                .index(1)
+                .validator_os(move |_: &std::ffi::OsStr| -> Result<(), String> {
+                    os_order.lock().unwrap().push("os");
+                    Ok(())
+                })
+                .validator(move |_: &str| -> Result<(), String> {
+                    str_order.lock().unwrap().push("str");
+                    Ok(())
+                }),
+        )
+        .try_get_matches_from(vec!["prog", "some.cfg"]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    assert_eq!(*order.lock().unwrap(), ["os", "str"]);
+}
+
+#[cfg(unix)]
+#[test]
+fn str_validator_rejects_invalid_utf8() {
+    use std::ffi::OsString;
+    use std::os::unix::ffi::OsStringExt;
+
+    let m = App::new("prog")
+        .arg(
+            Arg::new("file")
+                .index(1)
+                .validator(|_: &str| -> Result<(), String> { Ok(()) }),
+        )
+        .try_get_matches_from(vec![
+            OsString::from("prog"),
+            OsString::from_vec(vec![0xe9, b'.', b'c', b'f', b'g']),
+        ]);
+
+    assert!(m.is_err());
+    let err = m.unwrap_err();
+    assert_eq!(err.kind, clap::ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("value is not valid UTF-8"));
+}
+
+#[test]
+fn failing_validator_os_skips_str_validator() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("file")
+                .index(1)
+                .validator_os(|_: &std::ffi::OsStr| -> Result<(), String> {
+                    Err(String::from("os says no"))
+                })
+                .validator(|_: &str| -> Result<(), String> {
+                    panic!("str validator must not run when validator_os fails")
+                }),
+        )
+        .try_get_matches_from(vec!["prog", "some.cfg"]);
+
+    assert!(m.is_err());
+    let err = m.unwrap_err();
+    assert_eq!(err.kind, clap::ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("os says no"));
+}
+
+#[test]
+fn has_validator_reports_either_kind() {
+    let arg = Arg::new("port").validator(|v: &str| v.parse::<u16>().map_err(|e| e.to_string()));
+    assert!(arg.has_validator());
+
+    let arg = Arg::new("file").validator_os(|_: &std::ffi::OsStr| -> Result<(), String> { Ok(()) });
+    assert!(arg.has_validator());
+
+    let arg = Arg::new("plain");
+    assert!(!arg.has_validator());
+}
+
+#[test]
+fn validator_named_receives_arg_name() {
+    use std::sync::{Arc, Mutex};
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_cl = seen.clone();
+    let res = App::new("test")
+        .arg(
+            Arg::new("input")
+                .long("input")
+                .takes_value(true)
+                .validator_named(move |name: &str, _: &str| -> Result<(), String> {
+                    seen_cl.lock().unwrap().push(name.to_string());
+                    Ok(())
+                }),
+        )
+        .try_get_matches_from(vec!["test", "--input", "file"]);
+
+    assert!(res.is_ok());
+    assert_eq!(*seen.lock().unwrap(), vec!["input".to_string()]);
+}
+
+#[test]
+fn validator_named_error_can_cite_arg() {
+    fn must_be_absolute(name: &str, v: &str) -> Result<(), String> {
+        if v.starts_with('/') {
+            Ok(())
+        } else {
+            Err(format!("path '{}' for --{} must be absolute", v, name))
+        }
+    }
+
+    let res = App::new("test")
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .takes_value(true)
+                .validator_named(must_be_absolute),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .takes_value(true)
+                .validator_named(must_be_absolute),
+        )
+        .try_get_matches_from(vec!["test", "--config", "/etc/a.cfg", "--output", "rel"]);
+
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, clap::ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("for --output"));
+}
+
+#[test]
+fn validator_warn_does_not_fail_parse() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("jobs")
+                .long("jobs")
+                .takes_value(true)
+                .validator_warn(|v| {
+                    if v.parse::<u32>().map_or(false, |n| n > 512) {
+                        Some(format!("'{}' jobs is suspiciously high", v))
+                    } else {
+                        None
+                    }
+                }),
+        )
+        .try_get_matches_from(vec!["prog", "--jobs", "1024"]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    assert_eq!(m.unwrap().value_of("jobs"), Some("1024"));
+}
+
+#[test]
+fn validator_warn_silent_when_value_fine() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("jobs")
+                .long("jobs")
+                .takes_value(true)
+                .validator_warn(|_| None),
+        )
+        .try_get_matches_from(vec!["prog", "--jobs", "4"]);
+
+    assert!(m.is_ok(), "{:?}", m.unwrap_err());
+    assert_eq!(m.unwrap().value_of("jobs"), Some("4"));
+}
+
+#[test]
+fn validator_with_matches_cross_field_err() {
+    let res = App::new("prog")
+        .arg(Arg::new("min").long("min").takes_value(true))
+        .arg(
+            Arg::new("max")
+                .long("max")
+                .takes_value(true)
+                .validator_with_matches(|v, m| {
+                    let min: u32 = m.value_of("min").unwrap_or("0").parse().unwrap_or(0);
+                    if v.parse::<u32>().map_or(false, |max| max >= min) {
+                        Ok(())
+                    } else {
+                        Err(String::from("--max must be >= --min"))
+                    }
+                }),
+        )
+        .try_get_matches_from(vec!["prog", "--min", "10", "--max", "5"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, clap::ErrorKind::ValueValidation);
+}
+
+#[test]
+fn validator_with_matches_cross_field_ok() {
+    let res = App::new("prog")
+        .arg(Arg::new("min").long("min").takes_value(true))
+        .arg(
+            Arg::new("max")
+                .long("max")
+                .takes_value(true)
+                .validator_with_matches(|v, m| {
+                    let min: u32 = m.value_of("min").unwrap_or("0").parse().unwrap_or(0);
+                    if v.parse::<u32>().map_or(false, |max| max >= min) {
+                        Ok(())
+                    } else {
+                        Err(String::from("--max must be >= --min"))
+                    }
+                }),
+        )
+        .try_get_matches_from(vec!["prog", "--min", "3", "--max", "5"]);
+
+    assert!(res.is_ok(), "{:?}", res.unwrap_err());
+    assert_eq!(res.unwrap().value_of("max"), Some("5"));
+}
+
+#[test]
+fn possible_values_checked_before_validator_by_default() {
+    let m = App::new("test")
+        .arg(
+            Arg::new("digit")
+                .long("digit")
+                .possible_values(&["1", "2", "3"])
+                .validator(|v| v.parse::<u32>().map_err(|e| e.to_string())),
+        )
+        .try_get_matches_from(vec!["test", "--digit", "x"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, clap::ErrorKind::InvalidValue);
+}
+
+#[test]
+fn validate_before_possible_values_runs_validator_first() {
+    let m = App::new("test")
+        .arg(
+            Arg::new("digit")
+                .long("digit")
+                .possible_values(&["1", "2", "3"])
+                .validator(|v| v.parse::<u32>().map_err(|e| e.to_string()))
+                .validate_before_possible_values(true),
+        )
+        .try_get_matches_from(vec!["test", "--digit", "x"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, clap::ErrorKind::ValueValidation);
+}
+
+#[test]
+fn validate_before_possible_values_still_checks_possible_values() {
+    // a value the validator accepts must still be one of the possible values
+    let m = App::new("test")
+        .arg(
+            Arg::new("digit")
+                .long("digit")
+                .possible_values(&["1", "2", "3"])
+                .validator(|v| v.parse::<u32>().map_err(|e| e.to_string()))
+                .validate_before_possible_values(true),
+        )
+        .try_get_matches_from(vec!["test", "--digit", "7"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, clap::ErrorKind::InvalidValue);
+}
+
+#[test]
+fn ranged_value_name_accepts_value_in_range() {
+    let res = App::new("test")
+        .arg(Arg::new("port").long("port").value_name_ranged("PORT", 1, 65535))
+        .try_get_matches_from(vec!["test", "--port", "8080"]);
+
+    assert!(res.is_ok(), "{:?}", res.unwrap_err());
+    assert_eq!(res.unwrap().value_of("port"), Some("8080"));
+}
+
+#[test]
+fn ranged_value_name_rejects_value_out_of_range() {
+    let res = App::new("test")
+        .arg(Arg::new("port").long("port").value_name_ranged("PORT", 1, 65535))
+        .try_get_matches_from(vec!["test", "--port", "70000"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, clap::ErrorKind::ValueValidation);
+}
+
+#[test]
+fn ranged_value_name_rejects_non_integer() {
+    let res = App::new("test")
+        .arg(Arg::new("port").long("port").value_name_ranged("PORT", 1, 65535))
+        .try_get_matches_from(vec!["test", "--port", "web"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, clap::ErrorKind::ValueValidation);
+}
+
+#[test]
+fn map_os_stores_transformed_value() {
+    let m = App::new("test")
+        .arg(Arg::new("file").long("file").takes_value(true).map_os(|v| {
+            let mut owned = std::ffi::OsString::from("/canonical/");
+            owned.push(v);
+            Ok::<_, String>(owned)
+        }))
+        .try_get_matches_from(vec!["test", "--file", "a.txt"])
+        .unwrap();
+
+    // the raw input is no longer observable; only the mapped value is stored
+    assert_eq!(m.value_of("file"), Some("/canonical/a.txt"));
+}
+
+#[test]
+fn map_os_error_rejects_value() {
+    let res = App::new("test")
+        .arg(Arg::new("file").long("file").takes_value(true).map_os(|v| {
+            if v == "bad" {
+                Err(String::from("unusable path"))
+            } else {
+                Ok(v.to_os_string())
+            }
+        }))
+        .try_get_matches_from(vec!["test", "--file", "bad"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, clap::ErrorKind::ValueValidation);
+}
+
+#[test]
+fn map_os_runs_per_value() {
+    let m = App::new("test")
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .takes_value(true)
+                .multiple_values(true)
+                .map_os(|v| {
+                    let mut owned = v.to_os_string();
+                    owned.push(".bak");
+                    Ok::<_, String>(owned)
+                }),
+        )
+        .try_get_matches_from(vec!["test", "--file", "a", "b"])
+        .unwrap();
+
+    assert_eq!(
+        m.values_of("file").unwrap().collect::<Vec<_>>(),
+        ["a.bak", "b.bak"]
+    );
+}
+
+#[test]
+fn validate_last_only_runs_validator_once() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&count);
+    let m = App::new("test")
+        .arg(
+            Arg::new("level")
+                .long("level")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .validator(move |v| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    v.parse::<u32>().map_err(|e| e.to_string())
+                })
+                .validate_last_only(true),
+        )
+        .try_get_matches_from(vec!["test", "--level", "1", "--level", "2", "--level", "3"])
+        .unwrap();
+
+    assert_eq!(m.values_of("level").unwrap().last(), Some("3"));
+    assert_eq!(count.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+#[test]
+fn validate_last_only_skips_intermediate_failures() {
+    let res = App::new("test")
+        .arg(
+            Arg::new("level")
+                .long("level")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .validator(|v| v.parse::<u32>().map_err(|e| e.to_string()))
+                .validate_last_only(true),
+        )
+        .try_get_matches_from(vec!["test", "--level", "bogus", "--level", "3"]);
+
+    assert!(res.is_ok(), "{:?}", res.unwrap_err());
+}
+
+#[test]
+fn validate_last_only_still_checks_final_value() {
+    let res = App::new("test")
+        .arg(
+            Arg::new("level")
+                .long("level")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .validator(|v| v.parse::<u32>().map_err(|e| e.to_string()))
+                .validate_last_only(true),
+        )
+        .try_get_matches_from(vec!["test", "--level", "1", "--level", "bogus"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, clap::ErrorKind::ValueValidation);
+}